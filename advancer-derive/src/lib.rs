@@ -0,0 +1,517 @@
+//! Derive macros for `advancer`, use them through the `derive` feature of `advancer`.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::spanned::Spanned;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Expr, ExprLit, Fields, Generics,
+    Ident, Lit, LitInt, LitStr, Result,
+};
+
+/// Derives `advancer::Decode`.
+///
+/// Container attributes:
+/// - `#[advancer(endian = "little" | "big" | "native")]` fixes the endianness of all fields.
+/// - `#[advancer(tag = "u8" | "u16" | "u32" | "u64")]` sets the enum tag type, `u8` by default.
+///
+/// Field attributes:
+/// - `#[advancer(endian = "little" | "big" | "native")]` fixes the endianness of the field.
+/// - `#[advancer(pad_before = N)]` / `#[advancer(pad_after = N)]` skips `N` padding bytes.
+/// - `#[advancer(skip)]` does not decode the field, using `Default` instead.
+///
+/// Enum tags are the variant discriminants, which must be integer literals if given.
+/// Unknown keys, `tag` on structs and any key on enum variants are compile errors.
+#[proc_macro_derive(Decode, attributes(advancer))]
+pub fn derive_decode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_decode(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `advancer::Encode`.
+///
+/// Takes the same attributes as `Decode`, padding is written as zeros and skipped fields are not written.
+#[proc_macro_derive(Encode, attributes(advancer))]
+pub fn derive_encode(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_encode(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Derives `advancer::Length` for newtypes, forwarding to the single field.
+#[proc_macro_derive(Length)]
+pub fn derive_length(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_length(&input)
+        .unwrap_or_else(|error| error.to_compile_error())
+        .into()
+}
+
+/// Where an `#[advancer(...)]` attribute sits, which decides the keys it accepts
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Place {
+    Struct,
+    Enum,
+    Variant,
+    Field,
+}
+
+impl Place {
+    /// The keys accepted here
+    fn keys(self) -> &'static [&'static str] {
+        match self {
+            Self::Struct => &["endian"],
+            Self::Enum => &["endian", "tag"],
+            Self::Variant => &[],
+            Self::Field => &["endian", "pad_before", "pad_after", "skip"],
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Struct => "structs",
+            Self::Enum => "enums",
+            Self::Variant => "enum variants",
+            Self::Field => "fields",
+        }
+    }
+}
+
+/// Parsed `#[advancer(...)]` attributes
+#[derive(Default)]
+struct Attrs {
+    endian: Option<TokenStream2>,
+    tag: Option<Ident>,
+    pad_before: Option<LitInt>,
+    pad_after: Option<LitInt>,
+    skip: bool,
+}
+
+impl Attrs {
+    /// Parses the attributes of an item at `place`, erroring on unknown or misplaced keys
+    fn parse(attrs: &[Attribute], place: Place) -> Result<Self> {
+        let mut out = Self::default();
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("advancer")) {
+            attr.parse_nested_meta(|meta| {
+                let known = ["endian", "tag", "pad_before", "pad_after", "skip"];
+                if let Some(key) = known.iter().find(|key| meta.path.is_ident(key)) {
+                    if !place.keys().contains(key) {
+                        return Err(syn::Error::new_spanned(
+                            &meta.path,
+                            format!("`{}` is not allowed on {}", key, place.name()),
+                        ));
+                    }
+                }
+                if meta.path.is_ident("endian") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    out.endian = Some(match value.value().as_str() {
+                        "little" | "le" => quote!(::advancer::Endian::Little),
+                        "big" | "be" => quote!(::advancer::Endian::Big),
+                        "native" | "ne" => quote!(::advancer::Endian::Native),
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "expected `little`, `big` or `native`",
+                            ))
+                        }
+                    });
+                } else if meta.path.is_ident("tag") {
+                    let value: LitStr = meta.value()?.parse()?;
+                    match value.value().as_str() {
+                        "u8" | "u16" | "u32" | "u64" => {
+                            out.tag = Some(Ident::new(&value.value(), value.span()))
+                        }
+                        _ => {
+                            return Err(syn::Error::new(
+                                value.span(),
+                                "expected `u8`, `u16`, `u32` or `u64`",
+                            ))
+                        }
+                    }
+                } else if meta.path.is_ident("pad_before") {
+                    out.pad_before = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("pad_after") {
+                    out.pad_after = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("skip") {
+                    out.skip = true;
+                } else {
+                    return Err(syn::Error::new_spanned(
+                        &meta.path,
+                        "unknown advancer attribute, expected one of `endian`, `tag`, `pad_before`, `pad_after` or `skip`",
+                    ));
+                }
+                Ok(())
+            })?;
+        }
+        Ok(out)
+    }
+
+    /// Parses the container attributes of `input`
+    fn parse_container(input: &DeriveInput) -> Result<Self> {
+        let place = match input.data {
+            Data::Enum(_) => Place::Enum,
+            _ => Place::Struct,
+        };
+        Self::parse(&input.attrs, place)
+    }
+
+    /// The endian expression for this item, falling back to `outer`
+    fn endian(&self, outer: &TokenStream2) -> TokenStream2 {
+        self.endian.clone().unwrap_or_else(|| outer.clone())
+    }
+}
+
+/// Adds `bound` to every type parameter
+fn add_bounds(generics: &Generics, bound: TokenStream2) -> Generics {
+    let mut generics = generics.clone();
+    let params: Vec<_> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+    let where_clause = generics.make_where_clause();
+    for param in params {
+        where_clause.predicates.push(parse_quote!(#param: #bound));
+    }
+    generics
+}
+
+/// Gets the tag value of each variant, following Rust's discriminant rules
+fn variant_tags(data: &syn::DataEnum) -> Result<Vec<u64>> {
+    let mut next = 0u64;
+    let mut tags = Vec::with_capacity(data.variants.len());
+    for variant in &data.variants {
+        if let Some((_, discriminant)) = &variant.discriminant {
+            next = match discriminant {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Int(value),
+                    ..
+                }) => value.base10_parse()?,
+                _ => {
+                    return Err(syn::Error::new(
+                        discriminant.span(),
+                        "discriminant must be an integer literal",
+                    ))
+                }
+            };
+        }
+        tags.push(next);
+        next = next.wrapping_add(1);
+    }
+    Ok(tags)
+}
+
+/// Binding names for the fields of a variant or struct.
+/// Prefixed so fields cannot shadow the parameters of the generated functions.
+fn field_bindings(fields: &Fields) -> Vec<Ident> {
+    (0..fields.len())
+        .map(|index| format_ident!("__field_{}", index))
+        .collect()
+}
+
+/// Constructs `path` from already decoded field bindings
+fn construct(path: TokenStream2, fields: &Fields, bindings: &[Ident]) -> TokenStream2 {
+    match fields {
+        Fields::Named(fields) => {
+            let names = fields.named.iter().map(|field| &field.ident);
+            quote!(#path { #(#names: #bindings),* })
+        }
+        Fields::Unnamed(_) => quote!(#path ( #(#bindings),* )),
+        Fields::Unit => path,
+    }
+}
+
+fn decode_fields(fields: &Fields, endian: &TokenStream2) -> Result<TokenStream2> {
+    let mut out = TokenStream2::new();
+    for (field, binding) in fields.iter().zip(field_bindings(fields)) {
+        let attrs = Attrs::parse(&field.attrs, Place::Field)?;
+        if let Some(pad) = &attrs.pad_before {
+            out.extend(
+                quote!(<__R as ::advancer::AdvanceBytes>::try_read_array::<#pad>(__reader)?;),
+            );
+        }
+        if attrs.skip {
+            out.extend(quote!(let #binding = ::core::default::Default::default();));
+        } else {
+            let ty = &field.ty;
+            let endian = attrs.endian(endian);
+            out.extend(quote!(
+                let #binding = <#ty as ::advancer::Decode>::decode_endian(__reader, #endian)?;
+            ));
+        }
+        if let Some(pad) = &attrs.pad_after {
+            out.extend(
+                quote!(<__R as ::advancer::AdvanceBytes>::try_read_array::<#pad>(__reader)?;),
+            );
+        }
+    }
+    Ok(out)
+}
+
+fn encode_fields(fields: &Fields, endian: &TokenStream2) -> Result<TokenStream2> {
+    let mut out = TokenStream2::new();
+    for (field, binding) in fields.iter().zip(field_bindings(fields)) {
+        let attrs = Attrs::parse(&field.attrs, Place::Field)?;
+        if let Some(pad) = &attrs.pad_before {
+            out.extend(quote!(::advancer::AdvanceWrite::write_zeroed(__writer, #pad)?;));
+        }
+        if !attrs.skip {
+            let endian = attrs.endian(endian);
+            out.extend(quote!(::advancer::Encode::encode_endian(#binding, __writer, #endian)?;));
+        }
+        if let Some(pad) = &attrs.pad_after {
+            out.extend(quote!(::advancer::AdvanceWrite::write_zeroed(__writer, #pad)?;));
+        }
+    }
+    Ok(out)
+}
+
+fn expand_decode(input: &DeriveInput) -> Result<TokenStream2> {
+    let attrs = Attrs::parse_container(input)?;
+    let endian = attrs.endian(&quote!(__endian));
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let decode = decode_fields(&data.fields, &endian)?;
+            let value = construct(quote!(Self), &data.fields, &field_bindings(&data.fields));
+            quote!(#decode ::core::result::Result::Ok(#value))
+        }
+        Data::Enum(data) => {
+            let tag_ty = attrs
+                .tag
+                .clone()
+                .unwrap_or_else(|| Ident::new("u8", Span::call_site()));
+            let mut arms = TokenStream2::new();
+            for (variant, tag) in data.variants.iter().zip(variant_tags(data)?) {
+                Attrs::parse(&variant.attrs, Place::Variant)?;
+                let ident = &variant.ident;
+                let tag = LitInt::new(&format!("{}{}", tag, tag_ty), variant.span());
+                let decode = decode_fields(&variant.fields, &endian)?;
+                let value = construct(
+                    quote!(Self::#ident),
+                    &variant.fields,
+                    &field_bindings(&variant.fields),
+                );
+                arms.extend(quote!(#tag => { #decode ::core::result::Result::Ok(#value) }));
+            }
+            quote!(
+                let __tag = <#tag_ty as ::advancer::Decode>::decode_endian(__reader, #endian)?;
+                match __tag {
+                    #arms
+                    __tag => ::core::result::Result::Err(
+                        ::advancer::AdvanceError::InvalidTag { tag: __tag as u64 },
+                    ),
+                }
+            )
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "unions cannot derive `Decode`",
+            ))
+        }
+    };
+
+    let generics = add_bounds(&input.generics, quote!(::advancer::Decode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::advancer::Decode for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn decode_endian<__R: ::advancer::AdvanceBytes>(
+                __reader: &mut __R,
+                __endian: ::advancer::Endian,
+            ) -> ::core::result::Result<Self, ::advancer::AdvanceError> {
+                #body
+            }
+        }
+    ))
+}
+
+fn expand_encode(input: &DeriveInput) -> Result<TokenStream2> {
+    let attrs = Attrs::parse_container(input)?;
+    let endian = attrs.endian(&quote!(__endian));
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let encode = encode_fields(&data.fields, &endian)?;
+            let pattern = construct(quote!(Self), &data.fields, &field_bindings(&data.fields));
+            quote!(
+                let #pattern = self;
+                #encode
+                ::core::result::Result::Ok(())
+            )
+        }
+        Data::Enum(data) => {
+            let tag_ty = attrs
+                .tag
+                .clone()
+                .unwrap_or_else(|| Ident::new("u8", Span::call_site()));
+            let mut arms = TokenStream2::new();
+            for (variant, tag) in data.variants.iter().zip(variant_tags(data)?) {
+                Attrs::parse(&variant.attrs, Place::Variant)?;
+                let ident = &variant.ident;
+                let tag = LitInt::new(&format!("{}{}", tag, tag_ty), variant.span());
+                let encode = encode_fields(&variant.fields, &endian)?;
+                let pattern = construct(
+                    quote!(Self::#ident),
+                    &variant.fields,
+                    &field_bindings(&variant.fields),
+                );
+                arms.extend(quote!(#pattern => {
+                    ::advancer::Encode::encode_endian(&#tag, __writer, #endian)?;
+                    #encode
+                }));
+            }
+            quote!(
+                match self {
+                    #arms
+                }
+                ::core::result::Result::Ok(())
+            )
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span(),
+                "unions cannot derive `Encode`",
+            ))
+        }
+    };
+
+    let generics = add_bounds(&input.generics, quote!(::advancer::Encode));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::advancer::Encode for #ident #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn encode_endian<__W: ::advancer::AdvanceWrite>(
+                &self,
+                __writer: &mut __W,
+                __endian: ::advancer::Endian,
+            ) -> ::core::result::Result<(), ::advancer::AdvanceError> {
+                #body
+            }
+        }
+    ))
+}
+
+fn expand_length(input: &DeriveInput) -> Result<TokenStream2> {
+    let field = match &input.data {
+        Data::Struct(data) if data.fields.len() == 1 => data.fields.iter().next().unwrap(),
+        _ => {
+            return Err(syn::Error::new(
+                input.ident.span(),
+                "`Length` can only be derived for structs with a single field",
+            ))
+        }
+    };
+    let member = match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => quote!(0),
+    };
+    let ty = &field.ty;
+
+    let mut generics = input.generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#ty: ::advancer::Length));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+    Ok(quote!(
+        #[automatically_derived]
+        impl #impl_generics ::advancer::Length for #ident #ty_generics #where_clause {
+            fn len(&self) -> usize {
+                <#ty as ::advancer::Length>::len(&self.#member)
+            }
+
+            fn locate(&self, error: ::advancer::AdvanceError) -> ::advancer::AdvanceError {
+                <#ty as ::advancer::Length>::locate(&self.#member, error)
+            }
+        }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{expand_decode, expand_encode};
+    use syn::{parse_quote, DeriveInput};
+
+    /// The error both `Decode` and `Encode` derives give for `input`.
+    fn error(input: DeriveInput) -> String {
+        let decode = expand_decode(&input).unwrap_err().to_string();
+        let encode = expand_encode(&input).unwrap_err().to_string();
+        assert_eq!(decode, encode);
+        decode
+    }
+
+    #[test]
+    fn accepted() {
+        let input: DeriveInput = parse_quote!(
+            #[advancer(endian = "big", tag = "u16")]
+            enum Message {
+                Ping,
+                Data(#[advancer(endian = "little", pad_before = 2, pad_after = 1)] u32),
+                Skip {
+                    #[advancer(skip)]
+                    cache: u8,
+                },
+            }
+        );
+        assert!(expand_decode(&input).is_ok());
+        assert!(expand_encode(&input).is_ok());
+    }
+
+    #[test]
+    fn misplaced() {
+        assert_eq!(
+            error(parse_quote!(
+                #[advancer(tag = "u8")]
+                struct Header(u8);
+            )),
+            "`tag` is not allowed on structs"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                #[advancer(skip)]
+                struct Header(u8);
+            )),
+            "`skip` is not allowed on structs"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                enum Message {
+                    #[advancer(endian = "big")]
+                    Ping,
+                }
+            )),
+            "`endian` is not allowed on enum variants"
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct Header(#[advancer(tag = "u8")] u8);
+            )),
+            "`tag` is not allowed on fields"
+        );
+    }
+
+    #[test]
+    fn unknown() {
+        let expected = "unknown advancer attribute, expected one of `endian`, `tag`, `pad_before`, `pad_after` or `skip`";
+        assert_eq!(
+            error(parse_quote!(
+                #[advancer(endianness = "big")]
+                struct Header(u8);
+            )),
+            expected
+        );
+        assert_eq!(
+            error(parse_quote!(
+                struct Header(#[advancer(padding = 2)] u8);
+            )),
+            expected
+        );
+    }
+}
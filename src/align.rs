@@ -0,0 +1,145 @@
+use crate::{not_enough_data, Advance, AdvanceError, Length};
+use core::ops::Deref;
+
+/// Advances a byte advancer to an alignment, skipping the padding in between
+pub trait AdvanceAlign: for<'a> Advance<'a, Element = u8> + Deref<Target = [u8]> {
+    /// The position alignment is measured from.
+    /// The address of the next byte for slices, the offset into the buffer for cursors.
+    fn align_position(&self) -> usize;
+
+    /// Gets the amount of padding needed to reach `align`.
+    /// Errors if `align` is not a power of two.
+    fn padding_to(&self, align: usize) -> Result<usize, AdvanceError> {
+        if !align.is_power_of_two() {
+            return Err(AdvanceError::InvalidAlignment { align });
+        }
+        Ok(self.align_position().wrapping_neg() & (align - 1))
+    }
+
+    /// Advances self forward to `align`.
+    /// Not named `align_to` as `[u8]::align_to` would shadow it on slices.
+    /// Panics if not enough data or `align` is not a power of two.
+    fn align_forward(&mut self, align: usize) {
+        if let Err(error) = self.try_align_forward(align) {
+            panic!("{}", error)
+        }
+    }
+
+    /// Advances self forward to `align`.
+    /// Errors if not enough data or `align` is not a power of two.
+    fn try_align_forward(&mut self, align: usize) -> Result<(), AdvanceError> {
+        let padding = self.padding_to(align)?;
+        self.try_advance(padding)?;
+        Ok(())
+    }
+
+    /// Advances self forward to `align`, checking the padding is all zeros.
+    /// Panics if not enough data, `align` is not a power of two, or the padding is not zeroed.
+    fn align_forward_strict(&mut self, align: usize) {
+        if let Err(error) = self.try_align_forward_strict(align) {
+            panic!("{}", error)
+        }
+    }
+
+    /// Advances self forward to `align`, checking the padding is all zeros.
+    /// Errors if not enough data, `align` is not a power of two, or the padding is not zeroed.
+    /// Self is not advanced on error.
+    fn try_align_forward_strict(&mut self, align: usize) -> Result<(), AdvanceError> {
+        let padding = self.padding_to(align)?;
+        let padding_bytes = self
+            .get(..padding)
+            .ok_or_else(|| not_enough_data(self, padding))?;
+        if let Some(index) = padding_bytes.iter().position(|byte| *byte != 0) {
+            return Err(AdvanceError::NonZeroPadding {
+                index,
+                value: padding_bytes[index],
+            });
+        }
+        // Safety: padding is not greater than the length of self
+        unsafe { self.advance_unchecked(padding) };
+        Ok(())
+    }
+
+    /// Advances self forward to `align` then by `amount`, returning the portion after the padding.
+    /// Panics if not enough data or `align` is not a power of two.
+    fn advance_aligned<'a>(
+        &'a mut self,
+        amount: usize,
+        align: usize,
+    ) -> <Self as Advance<'a>>::AdvanceOut {
+        match self.try_advance_aligned(amount, align) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward to `align` then by `amount`, returning the portion after the padding.
+    /// Errors if not enough data or `align` is not a power of two.
+    /// Self is not advanced on error.
+    fn try_advance_aligned<'a>(
+        &'a mut self,
+        amount: usize,
+        align: usize,
+    ) -> Result<<Self as Advance<'a>>::AdvanceOut, AdvanceError> {
+        let padding = self.padding_to(align)?;
+        check_aligned_len(self, padding, amount)?;
+        // Safety: padding is not greater than the length of self
+        unsafe { self.advance_unchecked(padding) };
+        // Safety: amount is not greater than the remaining length of self
+        Ok(unsafe { self.advance_unchecked(amount) })
+    }
+
+    /// Advances self forward to `align` then by `amount`, returning the portion after the padding.
+    /// Checks the padding is all zeros.
+    /// Panics if not enough data, `align` is not a power of two, or the padding is not zeroed.
+    fn advance_aligned_strict<'a>(
+        &'a mut self,
+        amount: usize,
+        align: usize,
+    ) -> <Self as Advance<'a>>::AdvanceOut {
+        match self.try_advance_aligned_strict(amount, align) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward to `align` then by `amount`, returning the portion after the padding.
+    /// Checks the padding is all zeros.
+    /// Errors if not enough data, `align` is not a power of two, or the padding is not zeroed.
+    /// Self is not advanced on error.
+    fn try_advance_aligned_strict<'a>(
+        &'a mut self,
+        amount: usize,
+        align: usize,
+    ) -> Result<<Self as Advance<'a>>::AdvanceOut, AdvanceError> {
+        let padding = self.padding_to(align)?;
+        check_aligned_len(self, padding, amount)?;
+        self.try_align_forward_strict(align)?;
+        // Safety: amount is not greater than the remaining length of self
+        Ok(unsafe { self.advance_unchecked(amount) })
+    }
+}
+
+/// Checks that `padding` then `amount` fit in `advancer`.
+fn check_aligned_len<L: Length + ?Sized>(
+    advancer: &L,
+    padding: usize,
+    amount: usize,
+) -> Result<(), AdvanceError> {
+    match padding.checked_add(amount) {
+        Some(needed) if needed <= advancer.len() => Ok(()),
+        needed => Err(not_enough_data(advancer, needed.unwrap_or(usize::MAX))),
+    }
+}
+
+impl AdvanceAlign for &'_ [u8] {
+    fn align_position(&self) -> usize {
+        self.as_ptr() as usize
+    }
+}
+
+impl AdvanceAlign for &'_ mut [u8] {
+    fn align_position(&self) -> usize {
+        self.as_ptr() as usize
+    }
+}
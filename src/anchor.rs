@@ -0,0 +1,270 @@
+//! [Anchor](https://www.anchor-lang.com) account discriminators.
+//!
+//! Anchor prefixes every account with the first 8 bytes of `sha256("account:<Name>")`.
+
+use crate::{AdvanceBytes, AdvanceError};
+
+/// An 8 byte Anchor discriminator
+pub type Discriminator = [u8; 8];
+
+/// Computes the discriminator for `name` in `namespace`, `sha256("<namespace>:<name>")[..8]`.
+pub const fn discriminator(namespace: &str, name: &str) -> Discriminator {
+    let hash = sha256(&[namespace.as_bytes(), b":", name.as_bytes()]);
+    let mut out = [0; 8];
+    let mut index = 0;
+    while index < 8 {
+        out[index] = hash[index];
+        index += 1;
+    }
+    out
+}
+
+/// Computes the discriminator of the account type `name`, `sha256("account:<name>")[..8]`.
+pub const fn account_discriminator(name: &str) -> Discriminator {
+    discriminator("account", name)
+}
+
+/// Checks Anchor discriminators off the front of a byte advancer
+pub trait ExpectDiscriminator: AdvanceBytes {
+    /// Reads a discriminator, checking it is `expected`.
+    /// Errors if not enough data or the discriminator does not match.
+    /// The discriminator is advanced over even if it does not match.
+    fn expect_discriminator(&mut self, expected: &Discriminator) -> Result<(), AdvanceError> {
+        let found = self.try_read_array()?;
+        if found == *expected {
+            Ok(())
+        } else {
+            Err(AdvanceError::DiscriminatorMismatch {
+                expected: *expected,
+                found,
+            })
+        }
+    }
+}
+
+impl<A: AdvanceBytes> ExpectDiscriminator for A {}
+
+/// Decodes an account after its discriminator
+pub type Decoder<'a, T> = fn(&mut &'a [u8]) -> Result<T, AdvanceError>;
+
+/// Maps discriminators to decoders, for accounts whose type is not known ahead of time
+#[derive(Debug)]
+pub struct Dispatch<'t, 'a, T> {
+    entries: &'t [(Discriminator, Decoder<'a, T>)],
+}
+
+impl<'t, 'a, T> Dispatch<'t, 'a, T> {
+    /// Creates a dispatch table from `(discriminator, decoder)` entries
+    pub const fn new(entries: &'t [(Discriminator, Decoder<'a, T>)]) -> Self {
+        Self { entries }
+    }
+
+    /// Gets the decoder for `discriminator`
+    pub fn get(&self, discriminator: &Discriminator) -> Option<Decoder<'a, T>> {
+        self.entries
+            .iter()
+            .find(|(entry, _)| entry == discriminator)
+            .map(|(_, decoder)| *decoder)
+    }
+
+    /// Reads a discriminator and decodes the rest of the account with its decoder.
+    /// Errors if not enough data, the discriminator is unknown, or the decoder errors.
+    pub fn decode(&self, data: &mut &'a [u8]) -> Result<T, AdvanceError> {
+        let found = data.try_read_array()?;
+        let decoder = self
+            .get(&found)
+            .ok_or(AdvanceError::UnknownDiscriminator { found })?;
+        decoder(data)
+    }
+}
+
+impl<T> Clone for Dispatch<'_, '_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Dispatch<'_, '_, T> {}
+
+const K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Compresses a single 64 byte block into `state`.
+const fn compress(mut state: [u32; 8], block: &[u8; 64]) -> [u32; 8] {
+    let mut w = [0u32; 64];
+    let mut i = 0;
+    while i < 16 {
+        w[i] = u32::from_be_bytes([
+            block[i * 4],
+            block[i * 4 + 1],
+            block[i * 4 + 2],
+            block[i * 4 + 3],
+        ]);
+        i += 1;
+    }
+    while i < 64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16]
+            .wrapping_add(s0)
+            .wrapping_add(w[i - 7])
+            .wrapping_add(s1);
+        i += 1;
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+    i = 0;
+    while i < 64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let ch = (e & f) ^ (!e & g);
+        let temp1 = h
+            .wrapping_add(s1)
+            .wrapping_add(ch)
+            .wrapping_add(K[i])
+            .wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let maj = (a & b) ^ (a & c) ^ (b & c);
+        let temp2 = s0.wrapping_add(maj);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp1.wrapping_add(temp2);
+        i += 1;
+    }
+
+    let added = [a, b, c, d, e, f, g, h];
+    i = 0;
+    while i < 8 {
+        state[i] = state[i].wrapping_add(added[i]);
+        i += 1;
+    }
+    state
+}
+
+/// SHA-256 of the concatenation of `parts`, usable in `const` contexts.
+const fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut state = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+    let mut block = [0u8; 64];
+    let mut block_len = 0;
+    let mut total_len: u64 = 0;
+
+    let mut part = 0;
+    while part < parts.len() {
+        let bytes = parts[part];
+        let mut index = 0;
+        while index < bytes.len() {
+            block[block_len] = bytes[index];
+            block_len += 1;
+            if block_len == 64 {
+                state = compress(state, &block);
+                block_len = 0;
+            }
+            index += 1;
+        }
+        total_len += bytes.len() as u64;
+        part += 1;
+    }
+
+    // Padding: a one bit, zeros, then the bit length as a big endian u64
+    block[block_len] = 0x80;
+    block_len += 1;
+    if block_len > 56 {
+        while block_len < 64 {
+            block[block_len] = 0;
+            block_len += 1;
+        }
+        state = compress(state, &block);
+        block_len = 0;
+    }
+    while block_len < 56 {
+        block[block_len] = 0;
+        block_len += 1;
+    }
+    let bit_len = (total_len * 8).to_be_bytes();
+    let mut index = 0;
+    while index < 8 {
+        block[56 + index] = bit_len[index];
+        index += 1;
+    }
+    state = compress(state, &block);
+
+    let mut out = [0u8; 32];
+    let mut word = 0;
+    while word < 8 {
+        let bytes = state[word].to_be_bytes();
+        let mut index = 0;
+        while index < 4 {
+            out[word * 4 + index] = bytes[index];
+            index += 1;
+        }
+        word += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::sha256;
+
+    /// Decodes a 64 character hex digest.
+    fn digest(hex: &str) -> [u8; 32] {
+        let mut out = [0; 32];
+        for (index, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[index * 2..index * 2 + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// Known answers from `sha256sum` for `len` repeated `a` bytes, around the block and padding boundaries.
+    #[test]
+    fn known_answers() {
+        let data = [b'a'; 120];
+        for (len, hex) in [
+            (
+                0,
+                "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+            ),
+            (
+                55,
+                "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318",
+            ),
+            (
+                56,
+                "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a",
+            ),
+            (
+                63,
+                "7d3e74a05d7db15bce4ad9ec0658ea98e3f06eeecf16b4c6fff2da457ddc2f34",
+            ),
+            (
+                64,
+                "ffe054fe7ae0cb6dc65c3af9b61d5209f439851db43d0ba5997337df154668eb",
+            ),
+            (
+                120,
+                "2f3d335432c70b580af0e8e1b3674a7c020d683aa5f73aaaedfdc55af904c21c",
+            ),
+        ] {
+            assert_eq!(sha256(&[&data[..len]]), digest(hex), "length {}", len);
+            for split in [0, 1.min(len), len / 2, len] {
+                let (first, second) = data[..len].split_at(split);
+                assert_eq!(sha256(&[first, &[], second]), digest(hex), "length {}", len);
+            }
+        }
+    }
+}
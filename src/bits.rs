@@ -0,0 +1,197 @@
+use crate::AdvanceError;
+use core::ops::{Deref, DerefMut};
+
+/// Order bits are read from and written to within each byte
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum BitOrder {
+    /// The most significant bit of a byte comes first, values are big endian across bytes
+    #[default]
+    MsbFirst,
+    /// The least significant bit of a byte comes first, values are little endian across bytes
+    LsbFirst,
+}
+
+/// Advances over a byte slice a bit at a time, for packed flags and sub-byte fields
+#[derive(Copy, Clone, Debug)]
+pub struct BitAdvancer<S> {
+    data: S,
+    position: usize,
+    order: BitOrder,
+}
+
+impl<S: Deref<Target = [u8]>> BitAdvancer<S> {
+    /// Creates a bit advancer over `data` starting at its first bit.
+    pub fn new(data: S, order: BitOrder) -> Self {
+        Self {
+            data,
+            position: 0,
+            order,
+        }
+    }
+
+    /// Gets the bit order.
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Gets the number of bits advanced over.
+    pub fn bit_position(&self) -> usize {
+        self.position
+    }
+
+    /// Gets the number of bits left.
+    pub fn remaining_bits(&self) -> usize {
+        self.data.len() * 8 - self.position
+    }
+
+    /// Checks that `bits` are left.
+    fn check_bits(&self, bits: u32) -> Result<(), AdvanceError> {
+        assert!(
+            bits <= u64::BITS,
+            "cannot advance more than 64 bits at once"
+        );
+        if self.remaining_bits() < bits as usize {
+            Err(AdvanceError::NotEnoughBits {
+                needed: bits as usize,
+                remaining: self.remaining_bits(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Reads a `bits` wide unsigned value.
+    /// Panics if not enough bits or `bits` is greater than 64.
+    pub fn read_bits(&mut self, bits: u32) -> u64 {
+        match self.try_read_bits(bits) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `bits` wide unsigned value.
+    /// Errors if not enough bits.
+    /// Panics if `bits` is greater than 64.
+    pub fn try_read_bits(&mut self, bits: u32) -> Result<u64, AdvanceError> {
+        self.check_bits(bits)?;
+        let mut value = 0;
+        let mut left = bits;
+        while left > 0 {
+            let offset = (self.position % 8) as u32;
+            let take = left.min(8 - offset);
+            let mask = u8::MAX >> (8 - take);
+            let byte = self.data[self.position / 8];
+            let chunk = match self.order {
+                BitOrder::MsbFirst => (byte >> (8 - offset - take)) & mask,
+                BitOrder::LsbFirst => (byte >> offset) & mask,
+            };
+            match self.order {
+                BitOrder::MsbFirst => value = (value << take) | u64::from(chunk),
+                BitOrder::LsbFirst => value |= u64::from(chunk) << (bits - left),
+            }
+            self.position += take as usize;
+            left -= take;
+        }
+        Ok(value)
+    }
+
+    /// Reads a `bits` wide two's complement signed value.
+    /// Panics if not enough bits or `bits` is greater than 64.
+    pub fn read_signed_bits(&mut self, bits: u32) -> i64 {
+        match self.try_read_signed_bits(bits) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `bits` wide two's complement signed value.
+    /// Errors if not enough bits.
+    /// Panics if `bits` is greater than 64.
+    pub fn try_read_signed_bits(&mut self, bits: u32) -> Result<i64, AdvanceError> {
+        let value = self.try_read_bits(bits)?;
+        if bits == 0 {
+            return Ok(0);
+        }
+        let shift = u64::BITS - bits;
+        Ok(((value << shift) as i64) >> shift)
+    }
+
+    /// Reads a single bit as a `bool`.
+    /// Panics if not enough bits.
+    pub fn read_bit(&mut self) -> bool {
+        self.read_bits(1) != 0
+    }
+
+    /// Reads a single bit as a `bool`.
+    /// Errors if not enough bits.
+    pub fn try_read_bit(&mut self) -> Result<bool, AdvanceError> {
+        self.try_read_bits(1).map(|bit| bit != 0)
+    }
+
+    /// Advances to the start of the next byte, if not already at the start of one.
+    pub fn align_to_byte(&mut self) {
+        self.position = self.position.next_multiple_of(8);
+    }
+
+    /// Gets the bytes after the current one, or from the current one if at the start of a byte.
+    pub fn remaining_bytes(&self) -> &[u8] {
+        &self.data[self.position.div_ceil(8)..]
+    }
+}
+
+impl<S: DerefMut<Target = [u8]>> BitAdvancer<S> {
+    /// Writes the low `bits` bits of `value`, higher bits are ignored.
+    /// Errors if not enough bits, nothing is written on error.
+    /// Panics if `bits` is greater than 64.
+    pub fn write_bits(&mut self, bits: u32, value: u64) -> Result<(), AdvanceError> {
+        self.check_bits(bits)?;
+        let mut left = bits;
+        while left > 0 {
+            let offset = (self.position % 8) as u32;
+            let take = left.min(8 - offset);
+            let mask = u8::MAX >> (8 - take);
+            let (chunk, shift) = match self.order {
+                BitOrder::MsbFirst => ((value >> (left - take)) as u8 & mask, 8 - offset - take),
+                BitOrder::LsbFirst => ((value >> (bits - left)) as u8 & mask, offset),
+            };
+            let byte = &mut self.data[self.position / 8];
+            *byte = (*byte & !(mask << shift)) | (chunk << shift);
+            self.position += take as usize;
+            left -= take;
+        }
+        Ok(())
+    }
+
+    /// Writes the low `bits` bits of the two's complement `value`, higher bits are ignored.
+    /// Errors if not enough bits, nothing is written on error.
+    /// Panics if `bits` is greater than 64.
+    pub fn write_signed_bits(&mut self, bits: u32, value: i64) -> Result<(), AdvanceError> {
+        self.write_bits(bits, value as u64)
+    }
+
+    /// Writes a single bit.
+    /// Errors if not enough bits.
+    pub fn write_bit(&mut self, value: bool) -> Result<(), AdvanceError> {
+        self.write_bits(1, value.into())
+    }
+
+    /// Gets the bytes after the current one mutably, or from the current one if at the start of a byte.
+    pub fn remaining_bytes_mut(&mut self) -> &mut [u8] {
+        let start = self.position.div_ceil(8);
+        &mut self.data[start..]
+    }
+}
+
+impl<'a> BitAdvancer<&'a [u8]> {
+    /// Consumes self, returning the bytes after the current one, or from the current one if at the start of a byte.
+    pub fn into_remaining_bytes(self) -> &'a [u8] {
+        &self.data[self.position.div_ceil(8)..]
+    }
+}
+
+impl<'a> BitAdvancer<&'a mut [u8]> {
+    /// Consumes self, returning the bytes after the current one, or from the current one if at the start of a byte.
+    pub fn into_remaining_bytes(self) -> &'a mut [u8] {
+        &mut self.data[self.position.div_ceil(8)..]
+    }
+}
@@ -0,0 +1,468 @@
+//! [Borsh](https://borsh.io) compatible decoding and encoding.
+//!
+//! Byte slices and strings decode zero-copy as `&[u8]` and `&str`, owned collections need the `alloc` feature, and hash maps and sets the `std` feature.
+//! Collections of zero sized types are rejected, as the reference implementation does.
+//! Enums are encoded as a `u8` variant index followed by the variant's fields,
+//! use [`read_variant_index`] and [`write_variant_index`] to implement them.
+
+use crate::decode::try_array_from_fn;
+use crate::{Advance, AdvanceBytes, AdvanceError, AdvanceWrite};
+#[cfg(feature = "alloc")]
+use alloc::{
+    boxed::Box,
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+    vec::Vec,
+};
+use core::mem::size_of;
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+/// Types that can be decoded from borsh, possibly borrowing from the data
+pub trait BorshDecode<'a>: Sized {
+    /// Decodes self off the front of `data`.
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError>;
+}
+
+/// Types that can be encoded to borsh
+pub trait BorshEncode {
+    /// Encodes self into the front of `writer`.
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError>;
+}
+
+/// Decodes a `T` from the whole of `data`.
+/// Errors if any data is left over.
+pub fn from_slice<'a, T: BorshDecode<'a>>(mut data: &'a [u8]) -> Result<T, AdvanceError> {
+    let value = T::decode_borsh(&mut data)?;
+    if data.is_empty() {
+        Ok(value)
+    } else {
+        Err(AdvanceError::TrailingData {
+            remaining: data.len(),
+        })
+    }
+}
+
+/// Encodes `value` into the front of `data`, returning the amount written.
+pub fn to_slice<T: BorshEncode + ?Sized>(
+    value: &T,
+    mut data: &mut [u8],
+) -> Result<usize, AdvanceError> {
+    let len = data.len();
+    value.encode_borsh(&mut data)?;
+    Ok(len - data.len())
+}
+
+/// Reads an enum variant index.
+pub fn read_variant_index(data: &mut &[u8]) -> Result<u8, AdvanceError> {
+    data.try_read_u8()
+}
+
+/// Writes an enum variant index.
+pub fn write_variant_index<W: AdvanceWrite>(writer: &mut W, index: u8) -> Result<(), AdvanceError> {
+    writer.write_u8(index)
+}
+
+/// Reads a `u32` collection length.
+pub fn read_length(data: &mut &[u8]) -> Result<usize, AdvanceError> {
+    let length = data.try_read_u32_le()?;
+    usize::try_from(length).map_err(|_| AdvanceError::LengthOverflow {
+        length: length.into(),
+    })
+}
+
+/// Writes a `u32` collection length.
+/// Errors if `length` does not fit in a `u32`.
+pub fn write_length<W: AdvanceWrite>(writer: &mut W, length: usize) -> Result<(), AdvanceError> {
+    let length = u32::try_from(length).map_err(|_| AdvanceError::LengthOverflow {
+        length: length as u64,
+    })?;
+    writer.write_u32_le(length)
+}
+
+macro_rules! impl_integer {
+    ($($ty:ty => $read:ident, $write:ident;)*) => {
+        $(
+            impl BorshDecode<'_> for $ty {
+                fn decode_borsh(data: &mut &[u8]) -> Result<Self, AdvanceError> {
+                    data.$read()
+                }
+            }
+
+            impl BorshEncode for $ty {
+                fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+                    writer.$write(*self)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer! {
+    u8 => try_read_u8, write_u8;
+    u16 => try_read_u16_le, write_u16_le;
+    u32 => try_read_u32_le, write_u32_le;
+    u64 => try_read_u64_le, write_u64_le;
+    u128 => try_read_u128_le, write_u128_le;
+    i8 => try_read_i8, write_i8;
+    i16 => try_read_i16_le, write_i16_le;
+    i32 => try_read_i32_le, write_i32_le;
+    i64 => try_read_i64_le, write_i64_le;
+    i128 => try_read_i128_le, write_i128_le;
+    bool => try_read_bool, write_bool;
+}
+
+macro_rules! impl_float {
+    ($($ty:ty => $read:ident, $write:ident;)*) => {
+        $(
+            /// NaN is rejected as it has no canonical encoding.
+            impl BorshDecode<'_> for $ty {
+                fn decode_borsh(data: &mut &[u8]) -> Result<Self, AdvanceError> {
+                    let value = data.$read()?;
+                    if value.is_nan() {
+                        Err(AdvanceError::NaN)
+                    } else {
+                        Ok(value)
+                    }
+                }
+            }
+
+            /// NaN is rejected as it has no canonical encoding.
+            impl BorshEncode for $ty {
+                fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+                    if self.is_nan() {
+                        Err(AdvanceError::NaN)
+                    } else {
+                        writer.$write(*self)
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float! {
+    f32 => try_read_f32_le, write_f32_le;
+    f64 => try_read_f64_le, write_f64_le;
+}
+
+impl BorshDecode<'_> for () {
+    fn decode_borsh(_data: &mut &[u8]) -> Result<Self, AdvanceError> {
+        Ok(())
+    }
+}
+
+impl BorshEncode for () {
+    fn encode_borsh<W: AdvanceWrite>(&self, _writer: &mut W) -> Result<(), AdvanceError> {
+        Ok(())
+    }
+}
+
+impl<'a, T: BorshDecode<'a>, const N: usize> BorshDecode<'a> for [T; N] {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        try_array_from_fn(|| T::decode_borsh(data))
+    }
+}
+
+impl<T: BorshEncode, const N: usize> BorshEncode for [T; N] {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        self.iter()
+            .try_for_each(|element| element.encode_borsh(writer))
+    }
+}
+
+/// Decodes zero-copy with the same layout as `Vec<u8>`.
+impl<'a> BorshDecode<'a> for &'a [u8] {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let mut rest = *data;
+        let length = read_length(&mut rest)?;
+        let out = rest.try_advance(length)?;
+        *data = rest;
+        Ok(out)
+    }
+}
+
+/// Decodes zero-copy with the same layout as `String`.
+impl<'a> BorshDecode<'a> for &'a str {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let mut rest = *data;
+        let bytes = <&[u8]>::decode_borsh(&mut rest)?;
+        let out = core::str::from_utf8(bytes).map_err(|error| AdvanceError::InvalidUtf8 {
+            offset: error.valid_up_to(),
+        })?;
+        *data = rest;
+        Ok(out)
+    }
+}
+
+/// Encodes with the same layout as `Vec<T>`.
+/// Errors if `T` is zero sized.
+impl<T: BorshEncode> BorshEncode for [T] {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        check_not_zero_sized::<T>()?;
+        write_length(writer, self.len())?;
+        self.iter()
+            .try_for_each(|element| element.encode_borsh(writer))
+    }
+}
+
+/// Encodes with the same layout as `String`.
+impl BorshEncode for str {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        write_length(writer, self.len())?;
+        writer.write_bytes(self.as_bytes())
+    }
+}
+
+impl<T: BorshEncode + ?Sized> BorshEncode for &T {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        T::encode_borsh(self, writer)
+    }
+}
+
+impl<'a, T: BorshDecode<'a>> BorshDecode<'a> for Option<T> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        match data.try_read_u8()? {
+            0 => Ok(None),
+            1 => T::decode_borsh(data).map(Some),
+            tag => Err(AdvanceError::InvalidTag { tag: tag.into() }),
+        }
+    }
+}
+
+impl<T: BorshEncode> BorshEncode for Option<T> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        match self {
+            None => writer.write_u8(0),
+            Some(value) => {
+                writer.write_u8(1)?;
+                value.encode_borsh(writer)
+            }
+        }
+    }
+}
+
+impl<'a, T: BorshDecode<'a>, E: BorshDecode<'a>> BorshDecode<'a> for Result<T, E> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        match data.try_read_u8()? {
+            0 => E::decode_borsh(data).map(Err),
+            1 => T::decode_borsh(data).map(Ok),
+            tag => Err(AdvanceError::InvalidTag { tag: tag.into() }),
+        }
+    }
+}
+
+impl<T: BorshEncode, E: BorshEncode> BorshEncode for Result<T, E> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        match self {
+            Err(error) => {
+                writer.write_u8(0)?;
+                error.encode_borsh(writer)
+            }
+            Ok(value) => {
+                writer.write_u8(1)?;
+                value.encode_borsh(writer)
+            }
+        }
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($($name:ident),+))*) => {
+        $(
+            impl<'a, $($name: BorshDecode<'a>),+> BorshDecode<'a> for ($($name,)+) {
+                fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+                    Ok(($($name::decode_borsh(data)?,)+))
+                }
+            }
+
+            impl<$($name: BorshEncode),+> BorshEncode for ($($name,)+) {
+                #[allow(non_snake_case)]
+                fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+                    let ($($name,)+) = self;
+                    $($name.encode_borsh(writer)?;)+
+                    Ok(())
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple! {
+    (A)
+    (A, B)
+    (A, B, C)
+    (A, B, C, D)
+    (A, B, C, D, E)
+    (A, B, C, D, E, F)
+    (A, B, C, D, E, F, G)
+    (A, B, C, D, E, F, G, H)
+}
+
+/// Checks `T` is not zero sized, as borsh requires for the elements of collections.
+/// A length prefix alone could otherwise demand any number of them without the data to back it.
+fn check_not_zero_sized<T>() -> Result<(), AdvanceError> {
+    if size_of::<T>() == 0 {
+        Err(AdvanceError::ZeroSizedElements)
+    } else {
+        Ok(())
+    }
+}
+
+/// Decodes `length` elements, reserving no more than the remaining data could hold.
+#[cfg(feature = "alloc")]
+fn decode_elements<'a, T: BorshDecode<'a>>(
+    data: &mut &'a [u8],
+    length: usize,
+) -> Result<Vec<T>, AdvanceError> {
+    check_not_zero_sized::<T>()?;
+    let mut out = Vec::with_capacity(length.min(data.len()));
+    for _ in 0..length {
+        out.push(T::decode_borsh(data)?);
+    }
+    Ok(out)
+}
+
+/// Checks that `entries` are in strictly ascending order, as borsh requires for maps and sets.
+#[cfg(feature = "alloc")]
+fn check_sorted<T: Ord>(entries: &[T]) -> Result<(), AdvanceError> {
+    match entries.windows(2).position(|pair| pair[0] >= pair[1]) {
+        Some(index) => Err(AdvanceError::UnsortedKeys { index: index + 1 }),
+        None => Ok(()),
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: BorshDecode<'a>> BorshDecode<'a> for Vec<T> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let length = read_length(data)?;
+        decode_elements(data, length)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BorshEncode> BorshEncode for Vec<T> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        self.as_slice().encode_borsh(writer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BorshDecode<'_> for String {
+    fn decode_borsh(data: &mut &[u8]) -> Result<Self, AdvanceError> {
+        <&str>::decode_borsh(data).map(String::from)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BorshEncode for String {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        self.as_str().encode_borsh(writer)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<'a, T: BorshDecode<'a>> BorshDecode<'a> for Box<T> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        T::decode_borsh(data).map(Box::new)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BorshEncode + ?Sized> BorshEncode for Box<T> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        T::encode_borsh(self, writer)
+    }
+}
+
+/// Keys must be in strictly ascending order.
+#[cfg(feature = "std")]
+impl<'a, K, V> BorshDecode<'a> for HashMap<K, V>
+where
+    K: BorshDecode<'a> + Ord + Hash,
+    V: BorshDecode<'a>,
+{
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let length = read_length(data)?;
+        let entries: Vec<(K, V)> = decode_elements(data, length)?;
+        check_sorted(&entries.iter().map(|(key, _)| key).collect::<Vec<_>>())?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+/// Entries are written in ascending key order.
+#[cfg(feature = "std")]
+impl<K: BorshEncode + Ord, V: BorshEncode> BorshEncode for HashMap<K, V> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        check_not_zero_sized::<(K, V)>()?;
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable_by_key(|(key, _)| *key);
+        entries.encode_borsh(writer)
+    }
+}
+
+/// Values must be in strictly ascending order.
+#[cfg(feature = "std")]
+impl<'a, T: BorshDecode<'a> + Ord + Hash> BorshDecode<'a> for HashSet<T> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let values = Vec::<T>::decode_borsh(data)?;
+        check_sorted(&values)?;
+        Ok(values.into_iter().collect())
+    }
+}
+
+/// Values are written in ascending order.
+#[cfg(feature = "std")]
+impl<T: BorshEncode + Ord> BorshEncode for HashSet<T> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        check_not_zero_sized::<T>()?;
+        let mut values: Vec<_> = self.iter().collect();
+        values.sort_unstable();
+        values.encode_borsh(writer)
+    }
+}
+
+/// Keys must be in strictly ascending order.
+#[cfg(feature = "alloc")]
+impl<'a, K: BorshDecode<'a> + Ord, V: BorshDecode<'a>> BorshDecode<'a> for BTreeMap<K, V> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let length = read_length(data)?;
+        let entries: Vec<(K, V)> = decode_elements(data, length)?;
+        check_sorted(&entries.iter().map(|(key, _)| key).collect::<Vec<_>>())?;
+        Ok(entries.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<K: BorshEncode, V: BorshEncode> BorshEncode for BTreeMap<K, V> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        check_not_zero_sized::<(K, V)>()?;
+        write_length(writer, self.len())?;
+        self.iter().try_for_each(|(key, value)| {
+            key.encode_borsh(writer)?;
+            value.encode_borsh(writer)
+        })
+    }
+}
+
+/// Values must be in strictly ascending order.
+#[cfg(feature = "alloc")]
+impl<'a, T: BorshDecode<'a> + Ord> BorshDecode<'a> for BTreeSet<T> {
+    fn decode_borsh(data: &mut &'a [u8]) -> Result<Self, AdvanceError> {
+        let values = Vec::<T>::decode_borsh(data)?;
+        check_sorted(&values)?;
+        Ok(values.into_iter().collect())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: BorshEncode> BorshEncode for BTreeSet<T> {
+    fn encode_borsh<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        check_not_zero_sized::<T>()?;
+        write_length(writer, self.len())?;
+        self.iter().try_for_each(|value| value.encode_borsh(writer))
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{AdvanceArray, AdvanceError};
+
+macro_rules! read_fns {
+    ($($ty:ty => $read:ident, $try_read:ident, $from:ident;)*) => {
+        $(
+            #[doc = concat!("Reads a `", stringify!($ty), "` using `", stringify!($ty), "::", stringify!($from), "`.")]
+            /// Panics if not enough data.
+            fn $read(&mut self) -> $ty {
+                <$ty>::$from(self.read_array())
+            }
+
+            #[doc = concat!("Reads a `", stringify!($ty), "` using `", stringify!($ty), "::", stringify!($from), "`.")]
+            /// Errors if not enough data.
+            fn $try_read(&mut self) -> Result<$ty, AdvanceError> {
+                self.try_read_array().map(<$ty>::$from)
+            }
+        )*
+    };
+}
+
+/// Reads primitives off the front of a byte advancer
+pub trait AdvanceBytes {
+    /// Reads `N` bytes, returning a copy of them.
+    /// Errors if not enough data.
+    fn try_read_array<const N: usize>(&mut self) -> Result<[u8; N], AdvanceError>;
+
+    /// Reads `N` bytes, returning a copy of them.
+    /// Panics if not enough data.
+    fn read_array<const N: usize>(&mut self) -> [u8; N] {
+        match self.try_read_array() {
+            Ok(array) => array,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `bool`, erroring if the byte is not `0` or `1`.
+    /// Panics if not enough data or the byte is invalid.
+    fn read_bool(&mut self) -> bool {
+        match self.try_read_bool() {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `bool`.
+    /// Errors if not enough data or the byte is not `0` or `1`.
+    fn try_read_bool(&mut self) -> Result<bool, AdvanceError> {
+        match self.try_read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            value => Err(AdvanceError::InvalidBool { value }),
+        }
+    }
+
+    read_fns! {
+        u8 => read_u8, try_read_u8, from_ne_bytes;
+        i8 => read_i8, try_read_i8, from_ne_bytes;
+
+        u16 => read_u16_le, try_read_u16_le, from_le_bytes;
+        u16 => read_u16_be, try_read_u16_be, from_be_bytes;
+        u16 => read_u16_ne, try_read_u16_ne, from_ne_bytes;
+        u32 => read_u32_le, try_read_u32_le, from_le_bytes;
+        u32 => read_u32_be, try_read_u32_be, from_be_bytes;
+        u32 => read_u32_ne, try_read_u32_ne, from_ne_bytes;
+        u64 => read_u64_le, try_read_u64_le, from_le_bytes;
+        u64 => read_u64_be, try_read_u64_be, from_be_bytes;
+        u64 => read_u64_ne, try_read_u64_ne, from_ne_bytes;
+        u128 => read_u128_le, try_read_u128_le, from_le_bytes;
+        u128 => read_u128_be, try_read_u128_be, from_be_bytes;
+        u128 => read_u128_ne, try_read_u128_ne, from_ne_bytes;
+
+        i16 => read_i16_le, try_read_i16_le, from_le_bytes;
+        i16 => read_i16_be, try_read_i16_be, from_be_bytes;
+        i16 => read_i16_ne, try_read_i16_ne, from_ne_bytes;
+        i32 => read_i32_le, try_read_i32_le, from_le_bytes;
+        i32 => read_i32_be, try_read_i32_be, from_be_bytes;
+        i32 => read_i32_ne, try_read_i32_ne, from_ne_bytes;
+        i64 => read_i64_le, try_read_i64_le, from_le_bytes;
+        i64 => read_i64_be, try_read_i64_be, from_be_bytes;
+        i64 => read_i64_ne, try_read_i64_ne, from_ne_bytes;
+        i128 => read_i128_le, try_read_i128_le, from_le_bytes;
+        i128 => read_i128_be, try_read_i128_be, from_be_bytes;
+        i128 => read_i128_ne, try_read_i128_ne, from_ne_bytes;
+
+        f32 => read_f32_le, try_read_f32_le, from_le_bytes;
+        f32 => read_f32_be, try_read_f32_be, from_be_bytes;
+        f32 => read_f32_ne, try_read_f32_ne, from_ne_bytes;
+        f64 => read_f64_le, try_read_f64_le, from_le_bytes;
+        f64 => read_f64_be, try_read_f64_be, from_be_bytes;
+        f64 => read_f64_ne, try_read_f64_ne, from_ne_bytes;
+    }
+}
+
+impl<A> AdvanceBytes for A
+where
+    A: for<'a> AdvanceArray<'a, Element = u8>,
+{
+    fn try_read_array<const N: usize>(&mut self) -> Result<[u8; N], AdvanceError> {
+        self.try_advance_array::<N>().map(|array| *array)
+    }
+}
@@ -0,0 +1,156 @@
+use crate::{Advance, AdvanceError};
+use core::mem::{align_of, size_of};
+use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
+
+/// Marker for plain old data, types that can be cast from any suitably aligned bytes
+///
+/// # Safety
+/// Implementors must be `Copy`, have no padding bytes, and be valid for every bit pattern.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            // Safety: Primitive integers and floats are valid for every bit pattern
+            unsafe impl Pod for $ty {}
+        )*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+// Safety: Arrays have no padding between elements and `T` is valid for every bit pattern
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Checks that `count` values of `T` can be cast from the start of `data`, returning the byte length.
+fn check_cast<T: Pod>(data: &[u8], count: usize) -> Result<usize, AdvanceError> {
+    let size = size_of::<T>()
+        .checked_mul(count)
+        .ok_or(AdvanceError::SizeOverflow {
+            size: size_of::<T>(),
+            count,
+        })?;
+    if data.len() < size {
+        return Err(AdvanceError::NotEnoughData {
+            needed: size,
+            remaining: data.len(),
+        });
+    }
+    let address = data.as_ptr() as usize;
+    if !address.is_multiple_of(align_of::<T>()) {
+        return Err(AdvanceError::Misaligned {
+            align: align_of::<T>(),
+            address,
+        });
+    }
+    Ok(size)
+}
+
+/// Advances a byte slice, casting the advanced over portion to [`Pod`] types
+pub trait AdvanceAs<'b> {
+    /// Advances self forward by the size of `T`, returning the advanced over portion as `T`.
+    /// Errors if not enough data or the data is misaligned for `T`.
+    fn try_advance_as<T: Pod>(&mut self) -> Result<&'b T, AdvanceError>;
+
+    /// Advances self forward by `count` `T`s, returning the advanced over portion as a slice of `T`.
+    /// Errors if not enough data or the data is misaligned for `T`.
+    fn try_advance_slice_as<T: Pod>(&mut self, count: usize) -> Result<&'b [T], AdvanceError>;
+
+    /// Advances self forward by the size of `T`, returning the advanced over portion as `T`.
+    /// Panics if not enough data or the data is misaligned for `T`.
+    fn advance_as<T: Pod>(&mut self) -> &'b T {
+        match self.try_advance_as() {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward by `count` `T`s, returning the advanced over portion as a slice of `T`.
+    /// Panics if not enough data or the data is misaligned for `T`.
+    fn advance_slice_as<T: Pod>(&mut self, count: usize) -> &'b [T] {
+        match self.try_advance_slice_as(count) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+/// Advances a mutable byte slice, casting the advanced over portion to mutable [`Pod`] types
+pub trait AdvanceAsMut<'b>: AdvanceAs<'b> {
+    /// Advances self forward by the size of `T`, returning the advanced over portion as `T`.
+    /// Errors if not enough data or the data is misaligned for `T`.
+    fn try_advance_as_mut<T: Pod>(&mut self) -> Result<&'b mut T, AdvanceError>;
+
+    /// Advances self forward by `count` `T`s, returning the advanced over portion as a slice of `T`.
+    /// Errors if not enough data or the data is misaligned for `T`.
+    fn try_advance_slice_as_mut<T: Pod>(
+        &mut self,
+        count: usize,
+    ) -> Result<&'b mut [T], AdvanceError>;
+
+    /// Advances self forward by the size of `T`, returning the advanced over portion as `T`.
+    /// Panics if not enough data or the data is misaligned for `T`.
+    fn advance_as_mut<T: Pod>(&mut self) -> &'b mut T {
+        match self.try_advance_as_mut() {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward by `count` `T`s, returning the advanced over portion as a slice of `T`.
+    /// Panics if not enough data or the data is misaligned for `T`.
+    fn advance_slice_as_mut<T: Pod>(&mut self, count: usize) -> &'b mut [T] {
+        match self.try_advance_slice_as_mut(count) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+impl<'b> AdvanceAs<'b> for &'b [u8] {
+    fn try_advance_as<T: Pod>(&mut self) -> Result<&'b T, AdvanceError> {
+        let size = check_cast::<T>(self, 1)?;
+        // Safety: size, alignment and validity were checked by `check_cast`
+        Ok(unsafe { &*self.advance_unchecked(size).as_ptr().cast::<T>() })
+    }
+
+    fn try_advance_slice_as<T: Pod>(&mut self, count: usize) -> Result<&'b [T], AdvanceError> {
+        let size = check_cast::<T>(self, count)?;
+        // Safety: size, alignment and validity were checked by `check_cast`
+        Ok(unsafe {
+            &*slice_from_raw_parts(self.advance_unchecked(size).as_ptr().cast::<T>(), count)
+        })
+    }
+}
+
+impl<'b> AdvanceAs<'b> for &'b mut [u8] {
+    fn try_advance_as<T: Pod>(&mut self) -> Result<&'b T, AdvanceError> {
+        self.try_advance_as_mut().map(|value| &*value)
+    }
+
+    fn try_advance_slice_as<T: Pod>(&mut self, count: usize) -> Result<&'b [T], AdvanceError> {
+        self.try_advance_slice_as_mut(count).map(|value| &*value)
+    }
+}
+
+impl<'b> AdvanceAsMut<'b> for &'b mut [u8] {
+    fn try_advance_as_mut<T: Pod>(&mut self) -> Result<&'b mut T, AdvanceError> {
+        let size = check_cast::<T>(self, 1)?;
+        // Safety: size, alignment and validity were checked by `check_cast`
+        Ok(unsafe { &mut *self.advance_unchecked(size).as_mut_ptr().cast::<T>() })
+    }
+
+    fn try_advance_slice_as_mut<T: Pod>(
+        &mut self,
+        count: usize,
+    ) -> Result<&'b mut [T], AdvanceError> {
+        let size = check_cast::<T>(self, count)?;
+        // Safety: size, alignment and validity were checked by `check_cast`
+        Ok(unsafe {
+            &mut *slice_from_raw_parts_mut(
+                self.advance_unchecked(size).as_mut_ptr().cast::<T>(),
+                count,
+            )
+        })
+    }
+}
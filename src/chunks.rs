@@ -0,0 +1,134 @@
+use crate::{AdvanceArray, AdvanceBackArray, Cursor};
+use core::iter::FusedIterator;
+
+/// Iterates over `N` element arrays from the front of a slice, a stable alternative to `array_chunks`.
+///
+/// Elements left over when the length is not a multiple of `N` are available from [`ArrayChunks::remainder`].
+#[derive(Debug)]
+pub struct ArrayChunks<'a, T, const N: usize> {
+    data: &'a [T],
+    remainder: &'a [T],
+}
+
+impl<'a, T, const N: usize> ArrayChunks<'a, T, N> {
+    /// Creates an iterator over the `N` element arrays in `data`.
+    /// Panics if `N` is zero.
+    pub fn new(data: &'a [T]) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        let (data, remainder) = data.split_at(data.len() - data.len() % N);
+        Self { data, remainder }
+    }
+
+    /// Gets the elements left over after the last array.
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a [T]> for ArrayChunks<'a, T, N> {
+    fn from(data: &'a [T]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<'a, T, const N: usize> From<Cursor<'a, T>> for ArrayChunks<'a, T, N> {
+    fn from(cursor: Cursor<'a, T>) -> Self {
+        Self::new(cursor.remaining())
+    }
+}
+
+impl<T, const N: usize> Clone for ArrayChunks<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data,
+            remainder: self.remainder,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayChunks<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data.try_advance_array().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayChunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.data.try_advance_back_array().ok()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayChunks<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ArrayChunks<'_, T, N> {}
+
+/// Iterates over mutable `N` element arrays from the front of a slice, a stable alternative to `array_chunks_mut`.
+///
+/// Elements left over when the length is not a multiple of `N` are available from [`ArrayChunksMut::into_remainder`].
+#[derive(Debug)]
+pub struct ArrayChunksMut<'a, T, const N: usize> {
+    data: &'a mut [T],
+    remainder: &'a mut [T],
+}
+
+impl<'a, T, const N: usize> ArrayChunksMut<'a, T, N> {
+    /// Creates an iterator over the mutable `N` element arrays in `data`.
+    /// Panics if `N` is zero.
+    pub fn new(data: &'a mut [T]) -> Self {
+        assert!(N != 0, "chunk size must be non-zero");
+        let len = data.len() - data.len() % N;
+        let (data, remainder) = data.split_at_mut(len);
+        Self { data, remainder }
+    }
+
+    /// Gets the elements left over after the last array.
+    pub fn remainder(&self) -> &[T] {
+        self.remainder
+    }
+
+    /// Gets the elements left over after the last array mutably.
+    pub fn remainder_mut(&mut self) -> &mut [T] {
+        self.remainder
+    }
+
+    /// Consumes self, returning the elements left over after the last array.
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.remainder
+    }
+}
+
+impl<'a, T, const N: usize> From<&'a mut [T]> for ArrayChunksMut<'a, T, N> {
+    fn from(data: &'a mut [T]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ArrayChunksMut<'a, T, N> {
+    type Item = &'a mut [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.data.try_advance_array().ok()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.data.len() / N;
+        (len, Some(len))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ArrayChunksMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.data.try_advance_back_array().ok()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ArrayChunksMut<'_, T, N> {}
+
+impl<T, const N: usize> FusedIterator for ArrayChunksMut<'_, T, N> {}
@@ -0,0 +1,185 @@
+use crate::AdvanceError;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// Storage for the field path of a [`ContextError`], outermost field first
+pub trait FieldPath: Default + Debug {
+    /// Adds a field outside of the existing ones
+    fn push_front(&mut self, field: &'static str);
+
+    /// Tells whether no fields have been added
+    fn is_empty(&self) -> bool;
+
+    /// Writes the path joined by `.`
+    fn fmt_path(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+
+/// A fixed capacity field path, keeping the innermost `N` fields
+#[derive(Copy, Clone, Debug)]
+pub struct ArrayPath<const N: usize> {
+    fields: [&'static str; N],
+    start: usize,
+    truncated: bool,
+}
+
+impl<const N: usize> ArrayPath<N> {
+    /// The stored fields, outermost first
+    pub fn fields(&self) -> &[&'static str] {
+        &self.fields[self.start..]
+    }
+
+    /// Tells whether outer fields were dropped for lack of capacity
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl<const N: usize> Default for ArrayPath<N> {
+    fn default() -> Self {
+        Self {
+            fields: [""; N],
+            start: N,
+            truncated: false,
+        }
+    }
+}
+
+impl<const N: usize> FieldPath for ArrayPath<N> {
+    fn push_front(&mut self, field: &'static str) {
+        if self.start == 0 {
+            self.truncated = true;
+        } else {
+            self.start -= 1;
+            self.fields[self.start] = field;
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.start == N && !self.truncated
+    }
+
+    fn fmt_path(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.truncated {
+            f.write_str("...")?;
+        }
+        for (index, field) in self.fields().iter().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(field)?;
+        }
+        Ok(())
+    }
+}
+
+/// An unbounded field path
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct VecPath {
+    /// Innermost field first
+    fields: alloc::vec::Vec<&'static str>,
+}
+
+#[cfg(feature = "alloc")]
+impl VecPath {
+    /// The stored fields, outermost first
+    pub fn fields(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.fields.iter().rev().copied()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl FieldPath for VecPath {
+    fn push_front(&mut self, field: &'static str) {
+        self.fields.push(field);
+    }
+
+    fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    fn fmt_path(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, field) in self.fields().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(field)?;
+        }
+        Ok(())
+    }
+}
+
+/// An [`AdvanceError`] with the path of the field being decoded when it occurred
+///
+/// Displays as `fleet.cargo_hold.amount: not enough data (needed 8, remaining 3 at offset 212)`,
+/// the offset only being known when decoding through an offset tracking advancer.
+#[derive(Debug)]
+pub struct ContextError<P = ArrayPath<8>> {
+    /// The underlying error
+    pub error: AdvanceError,
+    /// The field path, outermost first
+    pub path: P,
+}
+
+impl<P: FieldPath> From<AdvanceError> for ContextError<P> {
+    fn from(error: AdvanceError) -> Self {
+        Self {
+            error,
+            path: P::default(),
+        }
+    }
+}
+
+impl<P: FieldPath> Display for ContextError<P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            self.path.fmt_path(f)?;
+            f.write_str(": ")?;
+        }
+        match self.error {
+            AdvanceError::NotEnoughData { needed, remaining } => {
+                write!(
+                    f,
+                    "not enough data (needed {needed}, remaining {remaining})"
+                )
+            }
+            AdvanceError::NotEnoughDataAt {
+                needed,
+                remaining,
+                offset,
+                ..
+            } => write!(
+                f,
+                "not enough data (needed {needed}, remaining {remaining} at offset {offset})"
+            ),
+            ref error => Display::fmt(error, f),
+        }
+    }
+}
+
+impl<P: FieldPath> core::error::Error for ContextError<P> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Adds field context to advancing results
+pub trait Context<T, P: FieldPath> {
+    /// Adds `field` to the front of the error's field path.
+    /// `field` may itself be a dotted path.
+    fn context(self, field: &'static str) -> Result<T, ContextError<P>>;
+}
+
+impl<T, P: FieldPath> Context<T, P> for Result<T, AdvanceError> {
+    fn context(self, field: &'static str) -> Result<T, ContextError<P>> {
+        self.map_err(ContextError::from).context(field)
+    }
+}
+
+impl<T, P: FieldPath> Context<T, P> for Result<T, ContextError<P>> {
+    fn context(self, field: &'static str) -> Result<T, ContextError<P>> {
+        self.map_err(|mut error| {
+            error.path.push_front(field);
+            error
+        })
+    }
+}
@@ -0,0 +1,329 @@
+use crate::{next, Advance, AdvanceAlign, AdvanceArray, AdvanceError, AdvanceWrite, Length};
+use core::ops::{Deref, DerefMut};
+use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
+
+/// Where to seek a cursor from
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum SeekFrom {
+    /// An offset from the start of the buffer
+    Start(usize),
+    /// An offset from the end of the buffer, must not be positive
+    End(isize),
+    /// An offset from the current position
+    Current(isize),
+}
+
+/// A saved cursor position that can be restored to
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Checkpoint {
+    position: usize,
+}
+
+impl Checkpoint {
+    /// The position saved by this checkpoint
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+/// Resolves `from` against a buffer of `len` with the cursor at `position`.
+fn seek_position(from: SeekFrom, position: usize, len: usize) -> Result<usize, AdvanceError> {
+    let target = match from {
+        SeekFrom::Start(offset) => Some(offset),
+        SeekFrom::End(offset) => len.checked_add_signed(offset),
+        SeekFrom::Current(offset) => position.checked_add_signed(offset),
+    };
+    match target {
+        Some(target) if target <= len => Ok(target),
+        _ => Err(AdvanceError::SeekOutOfBounds { from, length: len }),
+    }
+}
+
+/// Advances over a shared slice while tracking the absolute position in it
+#[derive(Copy, Clone, Debug)]
+pub struct Cursor<'a, T> {
+    data: &'a [T],
+    position: usize,
+}
+
+impl<'a, T> Cursor<'a, T> {
+    /// Creates a new cursor at the start of `data`
+    pub fn new(data: &'a [T]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// The position of the cursor from the start of the buffer
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The data not yet advanced over
+    pub fn remaining(&self) -> &'a [T] {
+        &self.data[self.position..]
+    }
+
+    /// The data already advanced over
+    pub fn consumed(&self) -> &'a [T] {
+        &self.data[..self.position]
+    }
+
+    /// The whole buffer
+    pub fn get_ref(&self) -> &'a [T] {
+        self.data
+    }
+
+    /// Moves the cursor to a new position, returning it.
+    /// Errors if the new position is outside of the buffer.
+    pub fn seek(&mut self, from: SeekFrom) -> Result<usize, AdvanceError> {
+        self.position = seek_position(from, self.position, self.data.len())?;
+        Ok(self.position)
+    }
+
+    /// Moves the cursor back to the start of the buffer
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    /// Saves the current position
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            position: self.position,
+        }
+    }
+
+    /// Moves the cursor back to a saved position.
+    /// Panics if the checkpoint is outside of the buffer.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        assert!(checkpoint.position <= self.data.len());
+        self.position = checkpoint.position;
+    }
+}
+
+impl<'a, T> From<&'a [T]> for Cursor<'a, T> {
+    fn from(data: &'a [T]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T> Deref for Cursor<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.remaining()
+    }
+}
+
+impl<T> Length for Cursor<'_, T> {
+    fn len(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    /// Attaches the position of the cursor and the length of the buffer.
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        error.at(self.position, self.data.len())
+    }
+}
+
+impl<'a, 'b, T> Advance<'a> for Cursor<'b, T> {
+    type Element = T;
+    type AdvanceOut = &'b [T];
+
+    unsafe fn advance_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety: Caller guarantees amount is not greater than the remaining length
+        let out = &*slice_from_raw_parts(self.data.as_ptr().add(self.position), amount);
+        self.position += amount;
+        out
+    }
+}
+
+impl<'a, 'b, T> AdvanceArray<'a> for Cursor<'b, T> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = &'b [T; N]
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &*(
+            // Safety: Same requirements as this function
+            self.advance_unchecked(N).as_ptr().cast::<[T; N]>()
+        )
+    }
+}
+
+impl<'b, T> next::Advance for Cursor<'b, T> {
+    type Element = T;
+    type AdvanceOut = &'b [T];
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety: Caller guarantees amount is not greater than the remaining length
+        let out = &*slice_from_raw_parts(self.data.as_ptr().add(self.position), amount);
+        self.position += amount;
+        out
+    }
+}
+
+impl<'b, T> next::AdvanceArray for Cursor<'b, T> {
+    type Element = T;
+    type AdvanceOut<const N: usize> = &'b [T; N];
+
+    unsafe fn advance_array_unchecked<const N: usize>(&mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &*(
+            // Safety: Same requirements as this function
+            next::Advance::advance_unchecked(self, N)
+                .as_ptr()
+                .cast::<[T; N]>()
+        )
+    }
+}
+
+impl AdvanceAlign for Cursor<'_, u8> {
+    fn align_position(&self) -> usize {
+        self.position
+    }
+}
+
+/// Advances over a mutable slice while tracking the absolute position in it.
+///
+/// Advanced over portions borrow the cursor, so seeking backwards can never alias them.
+#[derive(Debug)]
+pub struct CursorMut<'a, T> {
+    data: &'a mut [T],
+    position: usize,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    /// Creates a new cursor at the start of `data`
+    pub fn new(data: &'a mut [T]) -> Self {
+        Self { data, position: 0 }
+    }
+
+    /// The position of the cursor from the start of the buffer
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The data not yet advanced over
+    pub fn remaining(&self) -> &[T] {
+        &self.data[self.position..]
+    }
+
+    /// The data not yet advanced over
+    pub fn remaining_mut(&mut self) -> &mut [T] {
+        &mut self.data[self.position..]
+    }
+
+    /// The data already advanced over
+    pub fn consumed(&self) -> &[T] {
+        &self.data[..self.position]
+    }
+
+    /// The whole buffer
+    pub fn get_ref(&self) -> &[T] {
+        self.data
+    }
+
+    /// Splits the buffer into the consumed and remaining data, consuming the cursor
+    pub fn into_parts(self) -> (&'a mut [T], &'a mut [T]) {
+        self.data.split_at_mut(self.position)
+    }
+
+    /// Moves the cursor to a new position, returning it.
+    /// Errors if the new position is outside of the buffer.
+    pub fn seek(&mut self, from: SeekFrom) -> Result<usize, AdvanceError> {
+        self.position = seek_position(from, self.position, self.data.len())?;
+        Ok(self.position)
+    }
+
+    /// Moves the cursor back to the start of the buffer
+    pub fn rewind(&mut self) {
+        self.position = 0;
+    }
+
+    /// Saves the current position
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            position: self.position,
+        }
+    }
+
+    /// Moves the cursor back to a saved position.
+    /// Panics if the checkpoint is outside of the buffer.
+    pub fn restore(&mut self, checkpoint: Checkpoint) {
+        assert!(checkpoint.position <= self.data.len());
+        self.position = checkpoint.position;
+    }
+}
+
+impl<'a, T> From<&'a mut [T]> for CursorMut<'a, T> {
+    fn from(data: &'a mut [T]) -> Self {
+        Self::new(data)
+    }
+}
+
+impl<T> Deref for CursorMut<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.remaining()
+    }
+}
+
+impl<T> DerefMut for CursorMut<'_, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.remaining_mut()
+    }
+}
+
+impl<T> Length for CursorMut<'_, T> {
+    fn len(&self) -> usize {
+        self.data.len() - self.position
+    }
+
+    /// Attaches the position of the cursor and the length of the buffer.
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        error.at(self.position, self.data.len())
+    }
+}
+
+impl<'a, T: 'a> Advance<'a> for CursorMut<'_, T> {
+    type Element = T;
+    type AdvanceOut = &'a mut [T];
+
+    unsafe fn advance_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety: Caller guarantees amount is not greater than the remaining length
+        let out = &mut *slice_from_raw_parts_mut(self.data.as_mut_ptr().add(self.position), amount);
+        self.position += amount;
+        out
+    }
+}
+
+impl<'a, T> AdvanceArray<'a> for CursorMut<'_, T> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = &'a mut [T; N]
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &mut *(
+            // Safety: Same requirements as this function
+            self.advance_unchecked(N).as_mut_ptr().cast::<[T; N]>()
+        )
+    }
+}
+
+impl AdvanceAlign for CursorMut<'_, u8> {
+    fn align_position(&self) -> usize {
+        self.position
+    }
+}
+
+impl AdvanceWrite for CursorMut<'_, u8> {
+    fn try_advance_write(&mut self, amount: usize) -> Result<&mut [u8], AdvanceError> {
+        self.try_advance(amount)
+    }
+}
@@ -0,0 +1,158 @@
+use crate::{AdvanceBytes, AdvanceError, AdvanceWrite};
+use core::mem::{size_of, MaybeUninit};
+
+/// Byte order to decode and encode primitives with
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Endian {
+    /// Little endian
+    #[default]
+    Little,
+    /// Big endian
+    Big,
+    /// The target's endianness
+    Native,
+}
+
+/// Types that can be decoded off the front of a byte advancer
+pub trait Decode: Sized {
+    /// Decodes self, using `endian` for primitives without a fixed endianness.
+    fn decode_endian<R: AdvanceBytes>(reader: &mut R, endian: Endian)
+        -> Result<Self, AdvanceError>;
+
+    /// Decodes self as little endian.
+    fn decode<R: AdvanceBytes>(reader: &mut R) -> Result<Self, AdvanceError> {
+        Self::decode_endian(reader, Endian::Little)
+    }
+}
+
+/// Types that can be encoded into the front of a byte advancer
+pub trait Encode {
+    /// Encodes self, using `endian` for primitives without a fixed endianness.
+    fn encode_endian<W: AdvanceWrite>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+    ) -> Result<(), AdvanceError>;
+
+    /// Encodes self as little endian.
+    fn encode<W: AdvanceWrite>(&self, writer: &mut W) -> Result<(), AdvanceError> {
+        self.encode_endian(writer, Endian::Little)
+    }
+}
+
+macro_rules! impl_primitive {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Decode for $ty {
+                fn decode_endian<R: AdvanceBytes>(
+                    reader: &mut R,
+                    endian: Endian,
+                ) -> Result<Self, AdvanceError> {
+                    let bytes = reader.try_read_array::<{ size_of::<$ty>() }>()?;
+                    Ok(match endian {
+                        Endian::Little => <$ty>::from_le_bytes(bytes),
+                        Endian::Big => <$ty>::from_be_bytes(bytes),
+                        Endian::Native => <$ty>::from_ne_bytes(bytes),
+                    })
+                }
+            }
+
+            impl Encode for $ty {
+                fn encode_endian<W: AdvanceWrite>(
+                    &self,
+                    writer: &mut W,
+                    endian: Endian,
+                ) -> Result<(), AdvanceError> {
+                    writer.write_bytes(&match endian {
+                        Endian::Little => self.to_le_bytes(),
+                        Endian::Big => self.to_be_bytes(),
+                        Endian::Native => self.to_ne_bytes(),
+                    })
+                }
+            }
+        )*
+    };
+}
+
+impl_primitive!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl Decode for bool {
+    fn decode_endian<R: AdvanceBytes>(
+        reader: &mut R,
+        _endian: Endian,
+    ) -> Result<Self, AdvanceError> {
+        reader.try_read_bool()
+    }
+}
+
+impl Encode for bool {
+    fn encode_endian<W: AdvanceWrite>(
+        &self,
+        writer: &mut W,
+        _endian: Endian,
+    ) -> Result<(), AdvanceError> {
+        writer.write_bool(*self)
+    }
+}
+
+impl Decode for () {
+    fn decode_endian<R: AdvanceBytes>(
+        _reader: &mut R,
+        _endian: Endian,
+    ) -> Result<Self, AdvanceError> {
+        Ok(())
+    }
+}
+
+impl Encode for () {
+    fn encode_endian<W: AdvanceWrite>(
+        &self,
+        _writer: &mut W,
+        _endian: Endian,
+    ) -> Result<(), AdvanceError> {
+        Ok(())
+    }
+}
+
+/// Builds an array from `f` called once per element in order, stopping at the first error.
+pub(crate) fn try_array_from_fn<T, const N: usize>(
+    mut f: impl FnMut() -> Result<T, AdvanceError>,
+) -> Result<[T; N], AdvanceError> {
+    let mut array = [const { MaybeUninit::<T>::uninit() }; N];
+    for index in 0..N {
+        match f() {
+            Ok(value) => {
+                array[index].write(value);
+            }
+            Err(error) => {
+                for element in &mut array[..index] {
+                    // Safety: Elements before `index` were initialized
+                    unsafe { element.assume_init_drop() };
+                }
+                return Err(error);
+            }
+        }
+    }
+    // Safety: Every element was initialized and `MaybeUninit<T>` has the same layout as `T`
+    Ok(unsafe { array.as_ptr().cast::<[T; N]>().read() })
+}
+
+impl<T: Decode, const N: usize> Decode for [T; N] {
+    fn decode_endian<R: AdvanceBytes>(
+        reader: &mut R,
+        endian: Endian,
+    ) -> Result<Self, AdvanceError> {
+        try_array_from_fn(|| T::decode_endian(reader, endian))
+    }
+}
+
+impl<T: Encode, const N: usize> Encode for [T; N] {
+    fn encode_endian<W: AdvanceWrite>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+    ) -> Result<(), AdvanceError> {
+        self.iter()
+            .try_for_each(|element| element.encode_endian(writer, endian))
+    }
+}
@@ -0,0 +1,182 @@
+//! Forwarding impls so references to and borrows of advancers are advancers themselves.
+//!
+//! [`borrow_slice`] and [`borrow_slice_mut`] borrow a `RefCell<&mut [T]>`, such as Solana's `AccountInfo::data`,
+//! as an advancer over the slice in it.
+
+use crate::{next, Advance, AdvanceArray, AdvanceError, Length};
+use core::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
+
+/// Borrows the slice in `cell` as an advancer over it.
+/// Panics if `cell` is mutably borrowed.
+pub fn borrow_slice<'r, T>(cell: &'r RefCell<&mut [T]>) -> Ref<'r, [T]> {
+    Ref::map(cell.borrow(), |data| &**data)
+}
+
+/// Borrows the slice in `cell` as an advancer over it.
+/// Errors if `cell` is mutably borrowed.
+pub fn try_borrow_slice<'r, T>(cell: &'r RefCell<&mut [T]>) -> Result<Ref<'r, [T]>, BorrowError> {
+    cell.try_borrow().map(|data| Ref::map(data, |data| &**data))
+}
+
+/// Mutably borrows the slice in `cell` as an advancer over it.
+/// Panics if `cell` is borrowed.
+pub fn borrow_slice_mut<'r, T>(cell: &'r RefCell<&mut [T]>) -> RefMut<'r, [T]> {
+    RefMut::map(cell.borrow_mut(), |data| &mut **data)
+}
+
+/// Mutably borrows the slice in `cell` as an advancer over it.
+/// Errors if `cell` is borrowed.
+pub fn try_borrow_slice_mut<'r, T>(
+    cell: &'r RefCell<&mut [T]>,
+) -> Result<RefMut<'r, [T]>, BorrowMutError> {
+    cell.try_borrow_mut()
+        .map(|data| RefMut::map(data, |data| &mut **data))
+}
+
+impl<L: Length + ?Sized> Length for Ref<'_, L> {
+    fn len(&self) -> usize {
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
+    }
+}
+
+impl<L: Length + ?Sized> Length for RefMut<'_, L> {
+    fn len(&self) -> usize {
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
+    }
+}
+
+impl<'a, A: Advance<'a> + ?Sized> Advance<'a> for &mut A {
+    type Element = A::Element;
+    type AdvanceOut = A::AdvanceOut;
+
+    fn try_advance(&'a mut self, amount: usize) -> Result<Self::AdvanceOut, AdvanceError> {
+        A::try_advance(self, amount)
+    }
+
+    unsafe fn advance_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety: Same requirements as this function
+        A::advance_unchecked(self, amount)
+    }
+}
+
+impl<'a, A: AdvanceArray<'a> + ?Sized> AdvanceArray<'a> for &mut A {
+    type Element = A::Element;
+    type AdvanceOut<const N: usize>
+        = A::AdvanceOut<N>
+    where
+        Self: 'a;
+
+    fn try_advance_array<const N: usize>(
+        &'a mut self,
+    ) -> Result<Self::AdvanceOut<N>, AdvanceError> {
+        A::try_advance_array(self)
+    }
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safety: Same requirements as this function
+        A::advance_array_unchecked(self)
+    }
+}
+
+impl<A: next::Advance + ?Sized> next::Advance for &mut A {
+    type Element = A::Element;
+    type AdvanceOut = A::AdvanceOut;
+
+    fn try_advance(&mut self, amount: usize) -> Result<Self::AdvanceOut, AdvanceError> {
+        A::try_advance(self, amount)
+    }
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety: Same requirements as this function
+        A::advance_unchecked(self, amount)
+    }
+}
+
+impl<A: next::AdvanceArray + ?Sized> next::AdvanceArray for &mut A {
+    type Element = A::Element;
+    type AdvanceOut<const N: usize> = A::AdvanceOut<N>;
+
+    fn try_advance_array<const N: usize>(&mut self) -> Result<Self::AdvanceOut<N>, AdvanceError> {
+        A::try_advance_array(self)
+    }
+
+    unsafe fn advance_array_unchecked<const N: usize>(&mut self) -> Self::AdvanceOut<N> {
+        // Safety: Same requirements as this function
+        A::advance_array_unchecked(self)
+    }
+}
+
+/// Advances the borrowed slice, splitting the borrow so each output keeps the `RefCell` mutably borrowed.
+///
+/// Only the `RefMut` is advanced, the slice in the `RefCell` is left untouched.
+/// For Solana's `AccountInfo::data`, a `RefCell<&mut [u8]>`, borrow it with [`borrow_slice_mut`].
+impl<'a, 'r, T> Advance<'a> for RefMut<'r, [T]> {
+    type Element = T;
+    type AdvanceOut = RefMut<'r, [T]>;
+
+    unsafe fn advance_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety: `self` is overwritten below before it can be used or dropped again, nothing in between can panic
+        let this = core::ptr::read(self);
+        let (out, rest) = RefMut::map_split(this, |data| {
+            // Safety: Caller guarantees amount is not greater than the length of self
+            data.split_at_mut_unchecked(amount)
+        });
+        core::ptr::write(self, rest);
+        out
+    }
+}
+
+impl<'a, 'r, T> AdvanceArray<'a> for RefMut<'r, [T]> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = RefMut<'r, [T; N]>
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        RefMut::map(self.advance_unchecked(N), |data| {
+            // Safe conversion because returned array will always be same size as value passed in (`N`)
+            &mut *data.as_mut_ptr().cast::<[T; N]>()
+        })
+    }
+}
+
+/// Advances the borrowed slice, splitting the borrow so each output keeps the `RefCell` borrowed.
+///
+/// For Solana's `AccountInfo::data`, a `RefCell<&mut [u8]>`, borrow it with [`borrow_slice`].
+impl<'a, 'r, T> Advance<'a> for Ref<'r, [T]> {
+    type Element = T;
+    type AdvanceOut = Ref<'r, [T]>;
+
+    unsafe fn advance_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        let (out, rest) = Ref::map_split(Ref::clone(self), |data| {
+            // Safety: Caller guarantees amount is not greater than the length of self
+            data.split_at_unchecked(amount)
+        });
+        *self = rest;
+        out
+    }
+}
+
+impl<'a, 'r, T> AdvanceArray<'a> for Ref<'r, [T]> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = Ref<'r, [T; N]>
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        Ref::map(self.advance_unchecked(N), |data| {
+            // Safe conversion because returned array will always be same size as value passed in (`N`)
+            &*data.as_ptr().cast::<[T; N]>()
+        })
+    }
+}
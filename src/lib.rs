@@ -1,8 +1,62 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod anchor;
+pub mod borsh;
+pub mod forward;
+pub mod next;
+pub mod prefix;
+
+mod align;
+mod bits;
+mod bytes;
+mod cast;
+mod chunks;
+mod context;
+mod cursor;
+mod decode;
+#[cfg(feature = "alloc")]
+mod owned;
+mod peek;
+mod search;
+mod segments;
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+mod shared;
+mod split;
+mod string;
+mod varint;
+mod write;
+
+pub use align::AdvanceAlign;
+pub use bits::{BitAdvancer, BitOrder};
+pub use bytes::AdvanceBytes;
+pub use cast::{AdvanceAs, AdvanceAsMut, Pod};
+pub use chunks::{ArrayChunks, ArrayChunksMut};
+#[cfg(feature = "alloc")]
+pub use context::VecPath;
+pub use context::{ArrayPath, Context, ContextError, FieldPath};
+pub use cursor::{Checkpoint, Cursor, CursorMut, SeekFrom};
+pub use decode::{Decode, Encode, Endian};
+#[cfg(feature = "alloc")]
+pub use owned::{AdvanceVec, OwnedArray, VecAdvancer};
+pub use peek::AdvancePeek;
+pub use prefix::{AdvanceLenPrefixed, AdvanceLenPrefixedWrite};
+pub use search::{AdvanceSearch, SearchElement};
+pub use segments::{SegmentArray, Segments};
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+pub use shared::{SharedArray, SharedBytes};
+pub use split::{AdvanceSplit, SplitArrays};
+pub use string::{AdvanceStr, AdvanceStrWrite};
+pub use varint::{AdvanceVarint, AdvanceVarintWrite};
+pub use write::AdvanceWrite;
+
+#[cfg(feature = "derive")]
+pub use advancer_derive::{Decode, Encode, Length};
+
 use core::ops::Deref;
 use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
 use thiserror::Error;
@@ -15,6 +69,20 @@ pub trait Length {
     fn is_empty(&self) -> bool {
         self.len() == 0
     }
+
+    /// Attaches where self is in its buffer to `error`, for types that track their position.
+    /// Returns `error` unchanged by default.
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        error
+    }
+}
+
+/// The error for needing `needed` elements from the front of `advancer`, located by it.
+pub(crate) fn not_enough_data<L: Length + ?Sized>(advancer: &L, needed: usize) -> AdvanceError {
+    advancer.locate(AdvanceError::NotEnoughData {
+        needed,
+        remaining: advancer.len(),
+    })
 }
 
 impl<T> Length for [T] {
@@ -23,33 +91,91 @@ impl<T> Length for [T] {
     }
 }
 
-impl<T> Length for &'_ [T] {
+impl<T, const N: usize> Length for [T; N] {
     fn len(&self) -> usize {
-        <[T]>::len(self)
+        N
     }
 }
 
-impl<T> Length for &'_ mut [T] {
+impl<L: Length + ?Sized> Length for &'_ L {
     fn len(&self) -> usize {
-        <[T]>::len(self)
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
     }
 }
 
-impl<T, const N: usize> Length for [T; N] {
+impl<L: Length + ?Sized> Length for &'_ mut L {
     fn len(&self) -> usize {
-        N
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
     }
 }
 
-impl<T, const N: usize> Length for &'_ [T; N] {
+/// The length in bytes
+impl Length for str {
     fn len(&self) -> usize {
-        N
+        self.len()
     }
 }
 
-impl<T, const N: usize> Length for &'_ mut [T; N] {
+#[cfg(feature = "alloc")]
+impl<T> Length for alloc::vec::Vec<T> {
     fn len(&self) -> usize {
-        N
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Length for alloc::collections::VecDeque<T> {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+/// The length in bytes
+#[cfg(feature = "alloc")]
+impl Length for alloc::string::String {
+    fn len(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L: Length + ?Sized> Length for alloc::boxed::Box<L> {
+    fn len(&self) -> usize {
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<L: Length + ?Sized> Length for alloc::rc::Rc<L> {
+    fn len(&self) -> usize {
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
+    }
+}
+
+#[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+impl<L: Length + ?Sized> Length for alloc::sync::Arc<L> {
+    fn len(&self) -> usize {
+        L::len(self)
+    }
+
+    fn locate(&self, error: AdvanceError) -> AdvanceError {
+        L::locate(self, error)
     }
 }
 
@@ -57,6 +183,94 @@ impl<T, const N: usize> Length for &'_ mut [T; N] {
 pub enum AdvanceError {
     #[error("Not enough data, needed: `{needed}`, remaining: `{remaining}`")]
     NotEnoughData { needed: usize, remaining: usize },
+    #[error("Not enough data, needed: `{needed}`, remaining: `{remaining}`, offset: `{offset}`, length: `{length}`")]
+    NotEnoughDataAt {
+        needed: usize,
+        remaining: usize,
+        offset: usize,
+        length: usize,
+    },
+    #[error("Invalid bool, value: `{value}`")]
+    InvalidBool { value: u8 },
+    #[error("Misaligned data, required alignment: `{align}`, address: `{address:#x}`")]
+    Misaligned { align: usize, address: usize },
+    #[error("Size overflow, element size: `{size}`, count: `{count}`")]
+    SizeOverflow { size: usize, count: usize },
+    #[error("Invalid alignment, `{align}` is not a power of two")]
+    InvalidAlignment { align: usize },
+    #[error("Non-zero padding, index: `{index}`, value: `{value}`")]
+    NonZeroPadding { index: usize, value: u8 },
+    #[error("Seek out of bounds, from: `{from:?}`, length: `{length}`")]
+    SeekOutOfBounds { from: SeekFrom, length: usize },
+    #[error("Invalid tag, value: `{tag}`")]
+    InvalidTag { tag: u64 },
+    #[error("Trailing data, remaining: `{remaining}`")]
+    TrailingData { remaining: usize },
+    #[error("Length overflow, length: `{length}`")]
+    LengthOverflow { length: u64 },
+    #[error("Invalid UTF-8, offset: `{offset}`")]
+    InvalidUtf8 { offset: usize },
+    #[error("NaN is not allowed")]
+    NaN,
+    #[error("Keys not in strictly ascending order, index: `{index}`")]
+    UnsortedKeys { index: usize },
+    #[error("Collections of zero sized types are not allowed")]
+    ZeroSizedElements,
+    #[error("Discriminator mismatch, expected: `{expected:?}`, found: `{found:?}`")]
+    DiscriminatorMismatch { expected: [u8; 8], found: [u8; 8] },
+    #[error("Unknown discriminator, found: `{found:?}`")]
+    UnknownDiscriminator { found: [u8; 8] },
+    #[error("Overlong varint, trailing zero byte")]
+    VarintOverlong,
+    #[error("Varint overflow, does not fit in `{bits}` bits")]
+    VarintOverflow { bits: u32 },
+    #[error("Length limit exceeded, length: `{length}`, max: `{max}`")]
+    LengthLimitExceeded { length: usize, max: usize },
+    #[error("Missing NUL terminator")]
+    MissingNul,
+    #[error("Missing NUL terminator, offset: `{offset}`, length: `{length}`")]
+    MissingNulAt { offset: usize, length: usize },
+    #[error("Interior NUL, index: `{index}`")]
+    InteriorNul { index: usize },
+    #[error("Delimiter not found")]
+    DelimiterNotFound,
+    #[error("Not enough bits, needed: `{needed}`, remaining: `{remaining}`")]
+    NotEnoughBits { needed: usize, remaining: usize },
+    #[error("Allocation failed, requested: `{requested}` elements")]
+    AllocationFailed { requested: usize },
+    #[error("Scratch too small, needed: `{needed}`, capacity: `{capacity}`")]
+    ScratchTooSmall { needed: usize, capacity: usize },
+}
+
+impl AdvanceError {
+    /// Attaches the absolute `offset` into a buffer of `length` to a [`AdvanceError::NotEnoughData`]
+    /// or [`AdvanceError::MissingNul`], and makes the offset of an [`AdvanceError::InvalidUtf8`] found at `offset` absolute.
+    /// Other errors are returned unchanged.
+    pub fn at(self, offset: usize, length: usize) -> Self {
+        match self {
+            Self::NotEnoughData { needed, remaining } => Self::NotEnoughDataAt {
+                needed,
+                remaining,
+                offset,
+                length,
+            },
+            Self::MissingNul => Self::MissingNulAt { offset, length },
+            Self::InvalidUtf8 { offset: relative } => Self::InvalidUtf8 {
+                offset: offset + relative,
+            },
+            error => error,
+        }
+    }
+
+    /// The absolute offset the error occurred at, if known
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Self::NotEnoughDataAt { offset, .. } | Self::MissingNulAt { offset, .. } => {
+                Some(*offset)
+            }
+            _ => None,
+        }
+    }
 }
 
 // TODO: impl this const when const traits stabilized.
@@ -70,7 +284,9 @@ pub trait Advance<'a>: Length {
     /// Advances self forward by `amount`, returning the advanced over portion.
     /// Panics if not enough data.
     fn advance(&'a mut self, amount: usize) -> Self::AdvanceOut {
-        assert!(amount <= self.len());
+        if self.len() < amount {
+            panic!("{}", not_enough_data(self, amount))
+        }
         // Safety: amount is not greater than the length of self
         unsafe { self.advance_unchecked(amount) }
     }
@@ -79,10 +295,7 @@ pub trait Advance<'a>: Length {
     /// Errors if not enough data.
     fn try_advance(&'a mut self, amount: usize) -> Result<Self::AdvanceOut, AdvanceError> {
         if self.len() < amount {
-            Err(AdvanceError::NotEnoughData {
-                needed: amount,
-                remaining: self.len(),
-            })
+            Err(not_enough_data(self, amount))
         } else {
             // Safety: amount is not greater than the length of self
             Ok(unsafe { self.advance_unchecked(amount) })
@@ -110,7 +323,9 @@ pub trait AdvanceArray<'a>: Length {
     /// Advances self forward by `N`, returning the advanced over portion.
     /// Panics if not enough data.
     fn advance_array<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
-        assert!(N <= self.len());
+        if self.len() < N {
+            panic!("{}", not_enough_data(self, N))
+        }
         // Safety: N is not greater than the length of self
         unsafe { self.advance_array_unchecked() }
     }
@@ -121,10 +336,7 @@ pub trait AdvanceArray<'a>: Length {
         &'a mut self,
     ) -> Result<Self::AdvanceOut<N>, AdvanceError> {
         if self.len() < N {
-            Err(AdvanceError::NotEnoughData {
-                needed: N,
-                remaining: self.len(),
-            })
+            Err(not_enough_data(self, N))
         } else {
             // Safety: N is not greater than the length of self
             Ok(unsafe { self.advance_array_unchecked() })
@@ -139,6 +351,137 @@ pub trait AdvanceArray<'a>: Length {
     unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N>;
 }
 
+// TODO: impl this const when const traits stabilized.
+/// Advances a given slice from the back while maintaining lifetimes
+pub trait AdvanceBack<'a>: Advance<'a> {
+    /// Advances the back of self backward by `amount`, returning the advanced over portion.
+    /// Panics if not enough data.
+    fn advance_back(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        if self.len() < amount {
+            panic!("{}", not_enough_data(self, amount))
+        }
+        // Safety: amount is not greater than the length of self
+        unsafe { self.advance_back_unchecked(amount) }
+    }
+
+    /// Advances the back of self backward by `amount`, returning the advanced over portion.
+    /// Errors if not enough data.
+    fn try_advance_back(&'a mut self, amount: usize) -> Result<Self::AdvanceOut, AdvanceError> {
+        if self.len() < amount {
+            Err(not_enough_data(self, amount))
+        } else {
+            // Safety: amount is not greater than the length of self
+            Ok(unsafe { self.advance_back_unchecked(amount) })
+        }
+    }
+
+    /// Advances the back of self backward by `amount`, returning the advanced over portion.
+    /// Does not error if not enough data.
+    ///
+    /// # Safety
+    /// Caller must guarantee that `amount` is not greater than the length of self.
+    unsafe fn advance_back_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut;
+}
+
+// TODO: impl this const when const traits stabilized.
+/// Advances a given slice from the back giving back an array
+pub trait AdvanceBackArray<'a>: AdvanceArray<'a> {
+    /// Advances the back of self backward by `N`, returning the advanced over portion.
+    /// Panics if not enough data.
+    fn advance_back_array<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        if self.len() < N {
+            panic!("{}", not_enough_data(self, N))
+        }
+        // Safety: N is not greater than the length of self
+        unsafe { self.advance_back_array_unchecked() }
+    }
+
+    /// Advances the back of self backward by `N`, returning the advanced over portion.
+    /// Errors if not enough data.
+    fn try_advance_back_array<const N: usize>(
+        &'a mut self,
+    ) -> Result<Self::AdvanceOut<N>, AdvanceError> {
+        if self.len() < N {
+            Err(not_enough_data(self, N))
+        } else {
+            // Safety: N is not greater than the length of self
+            Ok(unsafe { self.advance_back_array_unchecked() })
+        }
+    }
+
+    /// Advances the back of self backward by `N`, returning the advanced over portion.
+    /// Does not error if not enough data.
+    ///
+    /// # Safety
+    /// Caller must guarantee that `N` is not greater than the length of self.
+    unsafe fn advance_back_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N>;
+}
+
+// TODO: impl this const when const traits stabilized.
+/// Advances a given slice by a single element from either end
+pub trait AdvanceOne<'a>: Length {
+    /// The element of the array
+    type Element;
+    /// The output of advancing
+    type AdvanceOut: Deref<Target = Self::Element>;
+
+    /// Advances self forward by one, returning the advanced over element.
+    /// Panics if not enough data.
+    fn advance_one(&'a mut self) -> Self::AdvanceOut {
+        if self.is_empty() {
+            panic!("{}", not_enough_data(self, 1))
+        }
+        // Safety: self is not empty
+        unsafe { self.advance_one_unchecked() }
+    }
+
+    /// Advances self forward by one, returning the advanced over element.
+    /// Errors if not enough data.
+    fn try_advance_one(&'a mut self) -> Result<Self::AdvanceOut, AdvanceError> {
+        if self.is_empty() {
+            Err(not_enough_data(self, 1))
+        } else {
+            // Safety: self is not empty
+            Ok(unsafe { self.advance_one_unchecked() })
+        }
+    }
+
+    /// Advances self forward by one, returning the advanced over element.
+    /// Does not error if not enough data.
+    ///
+    /// # Safety
+    /// Caller must guarantee that self is not empty.
+    unsafe fn advance_one_unchecked(&'a mut self) -> Self::AdvanceOut;
+
+    /// Advances the back of self backward by one, returning the advanced over element.
+    /// Panics if not enough data.
+    fn advance_back_one(&'a mut self) -> Self::AdvanceOut {
+        if self.is_empty() {
+            panic!("{}", not_enough_data(self, 1))
+        }
+        // Safety: self is not empty
+        unsafe { self.advance_back_one_unchecked() }
+    }
+
+    /// Advances the back of self backward by one, returning the advanced over element.
+    /// Errors if not enough data.
+    fn try_advance_back_one(&'a mut self) -> Result<Self::AdvanceOut, AdvanceError> {
+        if self.is_empty() {
+            Err(not_enough_data(self, 1))
+        } else {
+            // Safety: self is not empty
+            Ok(unsafe { self.advance_back_one_unchecked() })
+        }
+    }
+
+    /// Advances the back of self backward by one, returning the advanced over element.
+    /// Does not error if not enough data.
+    ///
+    /// # Safety
+    /// Caller must guarantee that self is not empty.
+    unsafe fn advance_back_one_unchecked(&'a mut self) -> Self::AdvanceOut;
+}
+
 impl<'a, 'b, T> Advance<'a> for &'b mut [T] {
     type Element = T;
     type AdvanceOut = &'b mut [T];
@@ -196,3 +539,73 @@ impl<'a, 'b, T> AdvanceArray<'a> for &'b [T] {
         )
     }
 }
+
+impl<'a, T> AdvanceBack<'a> for &mut [T] {
+    unsafe fn advance_back_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety neither slice overlaps and points to valid r/w data
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        *self = &mut *slice_from_raw_parts_mut(ptr, len - amount);
+        &mut *slice_from_raw_parts_mut(ptr.add(len - amount), amount)
+    }
+}
+
+impl<'a, T> AdvanceBackArray<'a> for &mut [T] {
+    unsafe fn advance_back_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &mut *(
+            // Safety: Same requirements as this function
+            self.advance_back_unchecked(N).as_mut_ptr().cast::<[T; N]>()
+        )
+    }
+}
+
+impl<'a, 'b, T> AdvanceOne<'a> for &'b mut [T] {
+    type Element = T;
+    type AdvanceOut = &'b mut T;
+
+    unsafe fn advance_one_unchecked(&'a mut self) -> Self::AdvanceOut {
+        // Safety: Same requirements as this function
+        &mut *self.advance_unchecked(1).as_mut_ptr()
+    }
+
+    unsafe fn advance_back_one_unchecked(&'a mut self) -> Self::AdvanceOut {
+        // Safety: Same requirements as this function
+        &mut *self.advance_back_unchecked(1).as_mut_ptr()
+    }
+}
+
+impl<'a, T> AdvanceBack<'a> for &[T] {
+    unsafe fn advance_back_unchecked(&'a mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety neither slice overlaps and points to valid r/w data
+        let len = self.len();
+        let ptr = self.as_ptr();
+        *self = &*slice_from_raw_parts(ptr, len - amount);
+        &*slice_from_raw_parts(ptr.add(len - amount), amount)
+    }
+}
+
+impl<'a, T> AdvanceBackArray<'a> for &[T] {
+    unsafe fn advance_back_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &*(
+            // Safety: Same requirements as this function
+            self.advance_back_unchecked(N).as_ptr().cast::<[T; N]>()
+        )
+    }
+}
+
+impl<'a, 'b, T> AdvanceOne<'a> for &'b [T] {
+    type Element = T;
+    type AdvanceOut = &'b T;
+
+    unsafe fn advance_one_unchecked(&'a mut self) -> Self::AdvanceOut {
+        // Safety: Same requirements as this function
+        &*self.advance_unchecked(1).as_ptr()
+    }
+
+    unsafe fn advance_back_one_unchecked(&'a mut self) -> Self::AdvanceOut {
+        // Safety: Same requirements as this function
+        &*self.advance_back_unchecked(1).as_ptr()
+    }
+}
@@ -0,0 +1,140 @@
+//! Advancing traits whose methods take `&mut self`.
+//!
+//! The outputs of [`Advance`] and [`AdvanceArray`] here borrow the underlying data rather than the advancer,
+//! so an advancer can be advanced repeatedly while earlier outputs are still alive and generic code can take
+//! `impl Advance` without higher ranked bounds.
+//!
+//! Method names match the crate root traits, so migrating is a matter of importing these instead.
+//! [`CursorMut`](crate::CursorMut) only implements the root traits, as its outputs must borrow it to stay sound when rewound.
+
+use crate::{not_enough_data, AdvanceError, Length};
+use core::ops::Deref;
+use core::ptr::{slice_from_raw_parts, slice_from_raw_parts_mut};
+
+/// Advances a given slice, with outputs borrowing the underlying data
+pub trait Advance: Length {
+    /// The element of the array
+    type Element;
+    /// The output of advancing
+    type AdvanceOut: Deref<Target = [Self::Element]>;
+
+    /// Advances self forward by `amount`, returning the advanced over portion.
+    /// Panics if not enough data.
+    fn advance(&mut self, amount: usize) -> Self::AdvanceOut {
+        if self.len() < amount {
+            panic!("{}", not_enough_data(self, amount))
+        }
+        // Safety: amount is not greater than the length of self
+        unsafe { self.advance_unchecked(amount) }
+    }
+
+    /// Advances self forward by `amount`, returning the advanced over portion.
+    /// Errors if not enough data.
+    fn try_advance(&mut self, amount: usize) -> Result<Self::AdvanceOut, AdvanceError> {
+        if self.len() < amount {
+            Err(not_enough_data(self, amount))
+        } else {
+            // Safety: amount is not greater than the length of self
+            Ok(unsafe { self.advance_unchecked(amount) })
+        }
+    }
+
+    /// Advances self forward by `amount`, returning the advanced over portion.
+    /// Does not error if not enough data.
+    ///
+    /// # Safety
+    /// Caller must guarantee that `amount` is not greater than the length of self.
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut;
+}
+
+/// Advances a given slice giving back an array, with outputs borrowing the underlying data
+pub trait AdvanceArray: Length {
+    /// The element of the array
+    type Element;
+    /// The output of advancing
+    type AdvanceOut<const N: usize>: Deref<Target = [Self::Element; N]>;
+
+    /// Advances self forward by `N`, returning the advanced over portion.
+    /// Panics if not enough data.
+    fn advance_array<const N: usize>(&mut self) -> Self::AdvanceOut<N> {
+        if self.len() < N {
+            panic!("{}", not_enough_data(self, N))
+        }
+        // Safety: N is not greater than the length of self
+        unsafe { self.advance_array_unchecked() }
+    }
+
+    /// Advances self forward by `N`, returning the advanced over portion.
+    /// Errors if not enough data.
+    fn try_advance_array<const N: usize>(&mut self) -> Result<Self::AdvanceOut<N>, AdvanceError> {
+        if self.len() < N {
+            Err(not_enough_data(self, N))
+        } else {
+            // Safety: N is not greater than the length of self
+            Ok(unsafe { self.advance_array_unchecked() })
+        }
+    }
+
+    /// Advances self forward by `N`, returning the advanced over portion.
+    /// Does not error if not enough data.
+    ///
+    /// # Safety
+    /// Caller must guarantee that `N` is not greater than the length of self.
+    unsafe fn advance_array_unchecked<const N: usize>(&mut self) -> Self::AdvanceOut<N>;
+}
+
+impl<'b, T> Advance for &'b mut [T] {
+    type Element = T;
+    type AdvanceOut = &'b mut [T];
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety neither slice overlaps and points to valid r/w data
+        let len = self.len();
+        let ptr = self.as_mut_ptr();
+        *self = &mut *slice_from_raw_parts_mut(ptr.add(amount), len - amount);
+        &mut *slice_from_raw_parts_mut(ptr, amount)
+    }
+}
+
+impl<'b, T> AdvanceArray for &'b mut [T] {
+    type Element = T;
+    type AdvanceOut<const N: usize> = &'b mut [T; N];
+
+    unsafe fn advance_array_unchecked<const N: usize>(&mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &mut *(
+            // Safety: Same requirements as this function
+            Advance::advance_unchecked(self, N)
+                .as_mut_ptr()
+                .cast::<[T; N]>()
+        )
+    }
+}
+
+impl<'b, T> Advance for &'b [T] {
+    type Element = T;
+    type AdvanceOut = &'b [T];
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        // Safety neither slice overlaps and points to valid r/w data
+        let len = self.len();
+        let ptr = self.as_ptr();
+        *self = &*slice_from_raw_parts(ptr.add(amount), len - amount);
+        &*slice_from_raw_parts(ptr, amount)
+    }
+}
+
+impl<'b, T> AdvanceArray for &'b [T] {
+    type Element = T;
+    type AdvanceOut<const N: usize> = &'b [T; N];
+
+    unsafe fn advance_array_unchecked<const N: usize>(&mut self) -> Self::AdvanceOut<N> {
+        // Safe conversion because returned array will always be same size as value passed in (`N`)
+        &*(
+            // Safety: Same requirements as this function
+            Advance::advance_unchecked(self, N)
+                .as_ptr()
+                .cast::<[T; N]>()
+        )
+    }
+}
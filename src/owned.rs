@@ -0,0 +1,288 @@
+//! Advancing owned buffers into owned pieces.
+//!
+//! A `Vec<T>` (or a `Box<[T]>` through `Vec::from`) is advanced through a [`VecAdvancer`] or its [`IntoIter`],
+//! which hand out elements from the front without shifting the rest, so advancing repeatedly stays linear.
+//! Arrays are returned by value as an [`OwnedArray`], so reading primitives never allocates.
+
+use crate::{Advance, AdvanceArray, AdvanceBytes, AdvanceError, Decode, Endian, Length};
+use alloc::collections::VecDeque;
+use alloc::vec::{IntoIter, Vec};
+use core::array;
+use core::fmt::{self, Debug, Formatter};
+use core::mem::size_of;
+use core::ops::{Deref, DerefMut};
+use core::ptr;
+
+/// An array of `N` elements advanced over from an owned buffer
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OwnedArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> OwnedArray<T, N> {
+    /// Consumes self, returning the array.
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Deref for OwnedArray<T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T, const N: usize> DerefMut for OwnedArray<T, N> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T, const N: usize> From<OwnedArray<T, N>> for [T; N] {
+    fn from(array: OwnedArray<T, N>) -> Self {
+        array.0
+    }
+}
+
+/// Advances an owned `Vec` from the front, moving advanced over elements out.
+///
+/// Only an offset into the `Vec` is advanced, the remaining elements are never shifted.
+pub struct VecAdvancer<T> {
+    /// Elements before `position` have been moved out
+    vec: Vec<T>,
+    position: usize,
+}
+
+impl<T> VecAdvancer<T> {
+    /// Creates an advancer at the start of `vec`
+    pub fn new(vec: Vec<T>) -> Self {
+        Self { vec, position: 0 }
+    }
+
+    /// The number of elements advanced over
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The elements not yet advanced over
+    pub fn remaining(&self) -> &[T] {
+        // Safety: Elements from `position` on are initialized
+        unsafe { &*ptr::slice_from_raw_parts(self.vec.as_ptr().add(self.position), self.len()) }
+    }
+
+    /// The elements not yet advanced over
+    pub fn remaining_mut(&mut self) -> &mut [T] {
+        let len = self.len();
+        // Safety: Elements from `position` on are initialized
+        unsafe {
+            &mut *ptr::slice_from_raw_parts_mut(self.vec.as_mut_ptr().add(self.position), len)
+        }
+    }
+
+    /// Consumes self, returning the elements not yet advanced over.
+    /// They are moved to the front of the original allocation.
+    pub fn into_vec(mut self) -> Vec<T> {
+        let len = self.len();
+        let mut vec = core::mem::take(&mut self.vec);
+        let position = core::mem::take(&mut self.position);
+        // Safety: The remaining elements are initialized and moved to the front before the length covers only them
+        unsafe {
+            ptr::copy(vec.as_ptr().add(position), vec.as_mut_ptr(), len);
+            vec.set_len(len);
+        }
+        vec
+    }
+}
+
+impl<T> From<Vec<T>> for VecAdvancer<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self::new(vec)
+    }
+}
+
+impl<T> Drop for VecAdvancer<T> {
+    fn drop(&mut self) {
+        let remaining: *mut [T] = self.remaining_mut();
+        // Safety: Advanced over elements were moved out, only the remaining ones are dropped and the length is
+        // cleared first so a panicking drop leaks rather than double drops
+        unsafe {
+            self.vec.set_len(0);
+            ptr::drop_in_place(remaining);
+        }
+    }
+}
+
+/// Clones the remaining elements, keeping the same position.
+impl<T: Clone> Clone for VecAdvancer<T> {
+    fn clone(&self) -> Self {
+        let mut vec = Vec::with_capacity(self.vec.len());
+        let slots = &mut vec.spare_capacity_mut()[self.position..];
+        for (slot, element) in slots.iter_mut().zip(self.remaining()) {
+            slot.write(element.clone());
+        }
+        // Safety: Elements from `position` on were just initialized, the ones before it count as moved out.
+        // A panicking clone leaves the length at zero, leaking the clones so far rather than dropping uninitialized ones
+        unsafe { vec.set_len(self.vec.len()) };
+        Self {
+            vec,
+            position: self.position,
+        }
+    }
+}
+
+impl<T: Debug> Debug for VecAdvancer<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VecAdvancer")
+            .field("remaining", &self.remaining())
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+impl<T> Deref for VecAdvancer<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.remaining()
+    }
+}
+
+impl<T> DerefMut for VecAdvancer<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.remaining_mut()
+    }
+}
+
+impl<T> Length for VecAdvancer<T> {
+    fn len(&self) -> usize {
+        self.vec.len() - self.position
+    }
+}
+
+impl<T> Advance<'_> for VecAdvancer<T> {
+    type Element = T;
+    type AdvanceOut = Vec<T>;
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        let mut out = Vec::with_capacity(amount);
+        // Safety: Caller guarantees `amount` elements remain, they are moved out by advancing past them
+        ptr::copy_nonoverlapping(
+            self.vec.as_ptr().add(self.position),
+            out.as_mut_ptr(),
+            amount,
+        );
+        out.set_len(amount);
+        self.position += amount;
+        out
+    }
+}
+
+impl<'a, T> AdvanceArray<'a> for VecAdvancer<T> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = OwnedArray<T, N>
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safety: Caller guarantees at least `N` elements remain, they are moved out by advancing past them
+        let array = self.vec.as_ptr().add(self.position).cast::<[T; N]>().read();
+        self.position += N;
+        OwnedArray(array)
+    }
+}
+
+impl<T> Length for IntoIter<T> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+}
+
+impl<T> Advance<'_> for IntoIter<T> {
+    type Element = T;
+    type AdvanceOut = Vec<T>;
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        self.by_ref().take(amount).collect()
+    }
+}
+
+impl<'a, T> AdvanceArray<'a> for IntoIter<T> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = OwnedArray<T, N>
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safety: Caller guarantees at least `N` elements remain
+        OwnedArray(array::from_fn(|_| self.next().unwrap_unchecked()))
+    }
+}
+
+impl<T> Advance<'_> for VecDeque<T> {
+    type Element = T;
+    type AdvanceOut = Vec<T>;
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        self.drain(..amount).collect()
+    }
+}
+
+impl<'a, T> AdvanceArray<'a> for VecDeque<T> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = OwnedArray<T, N>
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        // Safety: Caller guarantees at least `N` elements remain
+        OwnedArray(array::from_fn(|_| self.pop_front().unwrap_unchecked()))
+    }
+}
+
+/// Decodes runs of values off the front of a byte advancer into a `Vec`
+pub trait AdvanceVec: AdvanceBytes + Length + Sized {
+    /// Decodes `count` little endian `T`s.
+    /// Panics if `T` is zero sized, not enough data, a value is invalid, or the allocation fails.
+    fn advance_vec<T: Decode>(&mut self, count: usize) -> Vec<T> {
+        match self.try_advance_vec(count) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Decodes `count` little endian `T`s.
+    /// Errors if `T` is zero sized, not enough data, a value is invalid, or the allocation fails.
+    fn try_advance_vec<T: Decode>(&mut self, count: usize) -> Result<Vec<T>, AdvanceError> {
+        self.try_advance_vec_endian(count, Endian::Little)
+    }
+
+    /// Decodes `count` `T`s using `endian`.
+    /// Errors if `T` is zero sized, not enough data, a value is invalid, or the allocation fails.
+    fn try_advance_vec_endian<T: Decode>(
+        &mut self,
+        count: usize,
+        endian: Endian,
+    ) -> Result<Vec<T>, AdvanceError> {
+        // Zero sized values consume no data, so nothing would bound a bogus count
+        if size_of::<T>() == 0 {
+            return Err(AdvanceError::ZeroSizedElements);
+        }
+        let mut out = Vec::new();
+        // A bogus count can not reserve more than the remaining data, the rest grows as values decode
+        out.try_reserve_exact(count.min(self.len()))
+            .map_err(|_| AdvanceError::AllocationFailed { requested: count })?;
+        for _ in 0..count {
+            if out.len() == out.capacity() {
+                out.try_reserve(1)
+                    .map_err(|_| AdvanceError::AllocationFailed { requested: count })?;
+            }
+            out.push(T::decode_endian(self, endian)?);
+        }
+        Ok(out)
+    }
+}
+
+impl<A: AdvanceBytes + Length> AdvanceVec for A {}
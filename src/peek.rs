@@ -0,0 +1,76 @@
+use crate::{not_enough_data, Advance, AdvanceError, Length};
+use core::ops::Deref;
+
+/// Inspects the front of an advancer without consuming it, and discards data without returning it
+pub trait AdvancePeek<T>: Deref<Target = [T]> + Length {
+    /// Gets the first `amount` elements without advancing.
+    /// Panics if not enough data.
+    fn peek(&self, amount: usize) -> &[T] {
+        match self.try_peek(amount) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Gets the first `amount` elements without advancing.
+    /// Errors if not enough data.
+    fn try_peek(&self, amount: usize) -> Result<&[T], AdvanceError> {
+        self.get(..amount)
+            .ok_or_else(|| not_enough_data(self, amount))
+    }
+
+    /// Gets the first `N` elements without advancing.
+    /// Panics if not enough data.
+    fn peek_array<const N: usize>(&self) -> &[T; N] {
+        match self.try_peek_array() {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Gets the first `N` elements without advancing.
+    /// Errors if not enough data.
+    fn try_peek_array<const N: usize>(&self) -> Result<&[T; N], AdvanceError> {
+        // Safety: The peeked portion is exactly `N` elements
+        self.try_peek(N)
+            .map(|out| unsafe { &*out.as_ptr().cast::<[T; N]>() })
+    }
+
+    /// Gets `amount` elements starting `offset` elements in without advancing.
+    /// Panics if not enough data.
+    fn peek_at(&self, offset: usize, amount: usize) -> &[T] {
+        match self.try_peek_at(offset, amount) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Gets `amount` elements starting `offset` elements in without advancing.
+    /// Errors if not enough data.
+    fn try_peek_at(&self, offset: usize, amount: usize) -> Result<&[T], AdvanceError> {
+        let needed = offset.saturating_add(amount);
+        self.get(offset..needed)
+            .ok_or_else(|| not_enough_data(self, needed))
+    }
+
+    /// Advances self forward by `amount`, discarding the advanced over portion.
+    /// Errors if not enough data.
+    fn try_skip(&mut self, amount: usize) -> Result<(), AdvanceError>;
+
+    /// Advances self forward by `amount`, discarding the advanced over portion.
+    /// Panics if not enough data.
+    fn skip(&mut self, amount: usize) {
+        if let Err(error) = self.try_skip(amount) {
+            panic!("{}", error)
+        }
+    }
+}
+
+impl<T, A> AdvancePeek<T> for A
+where
+    A: Deref<Target = [T]> + for<'a> Advance<'a, Element = T>,
+{
+    fn try_skip(&mut self, amount: usize) -> Result<(), AdvanceError> {
+        self.try_advance(amount).map(drop)
+    }
+}
@@ -0,0 +1,166 @@
+//! Length prefixes for [`AdvanceLenPrefixed`] and [`AdvanceLenPrefixedWrite`].
+
+use crate::{
+    not_enough_data, Advance, AdvanceBytes, AdvanceError, AdvancePeek, AdvanceVarint,
+    AdvanceVarintWrite, AdvanceWrite, Length,
+};
+use core::ops::Deref;
+
+/// A length encoding that can precede a run of data
+pub trait LengthPrefix {
+    /// Reads a length.
+    /// Errors if not enough data, the prefix is invalid, or the length does not fit in a `usize`.
+    fn read_length<R: AdvanceBytes>(reader: &mut R) -> Result<usize, AdvanceError>;
+
+    /// Writes `length`.
+    /// Errors if not enough space or `length` does not fit in the prefix.
+    fn write_length<W: AdvanceWrite>(writer: &mut W, length: usize) -> Result<(), AdvanceError>;
+}
+
+fn to_usize<T: Into<u64> + Copy>(length: T) -> Result<usize, AdvanceError> {
+    usize::try_from(length.into()).map_err(|_| AdvanceError::LengthOverflow {
+        length: length.into(),
+    })
+}
+
+fn from_usize<T: TryFrom<usize>>(length: usize) -> Result<T, AdvanceError> {
+    T::try_from(length).map_err(|_| AdvanceError::LengthOverflow {
+        length: length as u64,
+    })
+}
+
+macro_rules! fixed_prefix {
+    ($($name:ident => $ty:ty, $read:ident, $write:ident, $doc:literal;)*) => {
+        $(
+            #[doc = $doc]
+            #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+            pub struct $name;
+
+            impl LengthPrefix for $name {
+                fn read_length<R: AdvanceBytes>(reader: &mut R) -> Result<usize, AdvanceError> {
+                    to_usize(reader.$read()?)
+                }
+
+                fn write_length<W: AdvanceWrite>(
+                    writer: &mut W,
+                    length: usize,
+                ) -> Result<(), AdvanceError> {
+                    writer.$write(from_usize(length)?)
+                }
+            }
+        )*
+    };
+}
+
+fixed_prefix! {
+    U8 => u8, try_read_u8, write_u8, "A `u8` length";
+    U16Le => u16, try_read_u16_le, write_u16_le, "A little endian `u16` length";
+    U16Be => u16, try_read_u16_be, write_u16_be, "A big endian `u16` length";
+    U32Le => u32, try_read_u32_le, write_u32_le, "A little endian `u32` length";
+    U32Be => u32, try_read_u32_be, write_u32_be, "A big endian `u32` length";
+    U64Le => u64, try_read_u64_le, write_u64_le, "A little endian `u64` length";
+    U64Be => u64, try_read_u64_be, write_u64_be, "A big endian `u64` length";
+}
+
+/// An unsigned LEB128 length of up to 64 bits
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Varint;
+
+impl LengthPrefix for Varint {
+    fn read_length<R: AdvanceBytes>(reader: &mut R) -> Result<usize, AdvanceError> {
+        to_usize(reader.try_read_varint_u64()?)
+    }
+
+    fn write_length<W: AdvanceWrite>(writer: &mut W, length: usize) -> Result<(), AdvanceError> {
+        writer.write_varint_u64(from_usize(length)?)
+    }
+}
+
+/// Solana's `compact-u16` (`ShortU16`), an unsigned LEB128 length of at most 3 bytes and `u16::MAX`
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct CompactU16;
+
+impl LengthPrefix for CompactU16 {
+    fn read_length<R: AdvanceBytes>(reader: &mut R) -> Result<usize, AdvanceError> {
+        to_usize(reader.try_read_varint_u16()?)
+    }
+
+    fn write_length<W: AdvanceWrite>(writer: &mut W, length: usize) -> Result<(), AdvanceError> {
+        writer.write_varint_u16(from_usize(length)?)
+    }
+}
+
+/// Reads a `P` length from a copy of the front of `advancer`, returning the sizes of the prefix and the run after it.
+/// Errors if the prefix is invalid, the length exceeds `max`, or not enough data follows the prefix.
+pub(crate) fn read_prefix<P, A>(advancer: &A, max: usize) -> Result<(usize, usize), AdvanceError>
+where
+    P: LengthPrefix,
+    A: Deref<Target = [u8]> + Length + ?Sized,
+{
+    let mut rest = &**advancer;
+    let length = P::read_length(&mut rest).map_err(|error| advancer.locate(error))?;
+    if length > max {
+        return Err(AdvanceError::LengthLimitExceeded { length, max });
+    }
+    let prefix = advancer.len() - rest.len();
+    if rest.len() < length {
+        return Err(not_enough_data(advancer, prefix.saturating_add(length)));
+    }
+    Ok((prefix, length))
+}
+
+/// Advances over runs of data preceded by their length.
+///
+/// Nothing is advanced over, not even the prefix, if the run cannot be read.
+pub trait AdvanceLenPrefixed<'a>: Advance<'a> + AdvancePeek<u8> + Sized {
+    /// Reads a `P` length and advances self forward by it.
+    /// Panics if not enough data or the prefix is invalid.
+    fn advance_len_prefixed<P: LengthPrefix>(&'a mut self) -> Self::AdvanceOut {
+        match self.try_advance_len_prefixed::<P>() {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `P` length and advances self forward by it.
+    /// Errors if not enough data or the prefix is invalid.
+    fn try_advance_len_prefixed<P: LengthPrefix>(
+        &'a mut self,
+    ) -> Result<Self::AdvanceOut, AdvanceError> {
+        self.try_advance_len_prefixed_max::<P>(usize::MAX)
+    }
+
+    /// Reads a `P` length and advances self forward by it, if it is at most `max`.
+    /// Panics if not enough data, the prefix is invalid, or the length exceeds `max`.
+    fn advance_len_prefixed_max<P: LengthPrefix>(&'a mut self, max: usize) -> Self::AdvanceOut {
+        match self.try_advance_len_prefixed_max::<P>(max) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `P` length and advances self forward by it, if it is at most `max`.
+    /// Errors if not enough data, the prefix is invalid, or the length exceeds `max`.
+    fn try_advance_len_prefixed_max<P: LengthPrefix>(
+        &'a mut self,
+        max: usize,
+    ) -> Result<Self::AdvanceOut, AdvanceError> {
+        let (prefix, length) = read_prefix::<P, _>(self, max)?;
+        self.try_skip(prefix)?;
+        self.try_advance(length)
+    }
+}
+
+impl<'a, A: Advance<'a> + AdvancePeek<u8>> AdvanceLenPrefixed<'a> for A {}
+
+/// Writes runs of data preceded by their length
+pub trait AdvanceLenPrefixedWrite: AdvanceWrite + Sized {
+    /// Writes the length of `bytes` as a `P`, then `bytes`.
+    /// Errors if not enough space or the length does not fit in `P`.
+    fn write_len_prefixed<P: LengthPrefix>(&mut self, bytes: &[u8]) -> Result<(), AdvanceError> {
+        P::write_length(self, bytes.len())?;
+        self.write_bytes(bytes)
+    }
+}
+
+impl<W: AdvanceWrite> AdvanceLenPrefixedWrite for W {}
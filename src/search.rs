@@ -0,0 +1,185 @@
+use crate::{next, Advance, AdvanceError};
+use core::mem::size_of;
+use core::ops::Deref;
+
+/// Elements that can be searched for delimiters.
+///
+/// The provided search compares element by element, `u8` overrides it with a word at a time search.
+pub trait SearchElement: PartialEq + Sized {
+    /// Finds the index of the first occurrence of `needle` in `haystack`.
+    fn find(haystack: &[Self], needle: &[Self]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        haystack
+            .windows(needle.len())
+            .position(|window| window == needle)
+    }
+}
+
+macro_rules! impl_search_element {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl SearchElement for $ty {}
+        )*
+    };
+}
+
+impl_search_element!(u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, char);
+
+impl SearchElement for u8 {
+    fn find(haystack: &[Self], needle: &[Self]) -> Option<usize> {
+        let Some((first, rest)) = needle.split_first() else {
+            return Some(0);
+        };
+        let mut start = 0;
+        while let Some(index) = find_byte(*first, &haystack[start..]) {
+            let candidate = start + index;
+            if haystack[candidate + 1..].starts_with(rest) {
+                return Some(candidate);
+            }
+            start = candidate + 1;
+        }
+        None
+    }
+}
+
+/// Finds the index of the first `byte` in `haystack`, checking a word at a time.
+fn find_byte(byte: u8, haystack: &[u8]) -> Option<usize> {
+    const WORD: usize = size_of::<usize>();
+    const LO: usize = usize::from_ne_bytes([0x01; WORD]);
+    const HI: usize = usize::from_ne_bytes([0x80; WORD]);
+    let repeated = usize::from_ne_bytes([byte; WORD]);
+
+    let mut chunks = haystack.chunks_exact(WORD);
+    let mut offset = 0;
+    for chunk in &mut chunks {
+        let mut bytes = [0; WORD];
+        bytes.copy_from_slice(chunk);
+        // A byte equal to `byte` becomes zero, which sets its high bit here
+        let word = usize::from_ne_bytes(bytes) ^ repeated;
+        if word.wrapping_sub(LO) & !word & HI != 0 {
+            break;
+        }
+        offset += WORD;
+    }
+    haystack[offset..]
+        .iter()
+        .position(|element| *element == byte)
+        .map(|index| offset + index)
+}
+
+/// Advances by searching the front of self, for predicates and delimiters
+pub trait AdvanceSearch<'a, T>: Advance<'a, Element = T> + Deref<Target = [T]> {
+    /// Advances self forward over the leading elements matching `predicate`, returning the advanced over portion.
+    fn advance_while(&'a mut self, mut predicate: impl FnMut(&T) -> bool) -> Self::AdvanceOut {
+        let amount = self
+            .iter()
+            .position(|element| !predicate(element))
+            .unwrap_or(self.len());
+        // Safety: amount is not greater than the length of self
+        unsafe { self.advance_unchecked(amount) }
+    }
+
+    /// Advances self forward up to the first element matching `predicate`, returning the advanced over portion.
+    /// Advances to the end if no element matches.
+    fn advance_until(&'a mut self, mut predicate: impl FnMut(&T) -> bool) -> Self::AdvanceOut {
+        self.advance_while(|element| !predicate(element))
+    }
+
+    /// Advances self forward up to the first occurrence of `delimiter`, returning the advanced over portion.
+    /// The delimiter is not advanced over.
+    /// Panics if the delimiter is not found.
+    fn advance_until_delimiter(&'a mut self, delimiter: &[T]) -> Self::AdvanceOut
+    where
+        T: SearchElement,
+    {
+        match self.try_advance_until_delimiter(delimiter) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward up to the first occurrence of `delimiter`, returning the advanced over portion.
+    /// The delimiter is not advanced over.
+    /// Errors if the delimiter is not found.
+    fn try_advance_until_delimiter(
+        &'a mut self,
+        delimiter: &[T],
+    ) -> Result<Self::AdvanceOut, AdvanceError>
+    where
+        T: SearchElement,
+    {
+        let amount = T::find(self, delimiter).ok_or(AdvanceError::DelimiterNotFound)?;
+        // Safety: amount is not greater than the length of self
+        Ok(unsafe { self.advance_unchecked(amount) })
+    }
+
+    /// Advances self forward past the first occurrence of `delimiter`, returning the advanced over portion including the delimiter.
+    /// See [`AdvanceSearch::advance_strip_delimiter`] to leave the delimiter out.
+    /// Panics if the delimiter is not found.
+    fn advance_past_delimiter(&'a mut self, delimiter: &[T]) -> Self::AdvanceOut
+    where
+        T: SearchElement,
+    {
+        match self.try_advance_past_delimiter(delimiter) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward past the first occurrence of `delimiter`, returning the advanced over portion including the delimiter.
+    /// Errors if the delimiter is not found.
+    fn try_advance_past_delimiter(
+        &'a mut self,
+        delimiter: &[T],
+    ) -> Result<Self::AdvanceOut, AdvanceError>
+    where
+        T: SearchElement,
+    {
+        let amount = T::find(self, delimiter).ok_or(AdvanceError::DelimiterNotFound)?;
+        // Safety: the delimiter was found within self
+        Ok(unsafe { self.advance_unchecked(amount + delimiter.len()) })
+    }
+
+    /// Advances self forward past the first occurrence of `delimiter`, returning the advanced over portion before the delimiter.
+    /// Panics if the delimiter is not found.
+    fn advance_strip_delimiter(&mut self, delimiter: &[T]) -> <Self as next::Advance>::AdvanceOut
+    where
+        T: SearchElement,
+        Self: next::Advance<Element = T>,
+    {
+        match self.try_advance_strip_delimiter(delimiter) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward past the first occurrence of `delimiter`, returning the advanced over portion before the delimiter.
+    /// Errors if the delimiter is not found.
+    fn try_advance_strip_delimiter(
+        &mut self,
+        delimiter: &[T],
+    ) -> Result<<Self as next::Advance>::AdvanceOut, AdvanceError>
+    where
+        T: SearchElement,
+        Self: next::Advance<Element = T>,
+    {
+        let amount = T::find(self, delimiter).ok_or(AdvanceError::DelimiterNotFound)?;
+        // Safety: the delimiter was found within self, directly after the first `amount` elements
+        unsafe {
+            let out = next::Advance::advance_unchecked(self, amount);
+            next::Advance::advance_unchecked(self, delimiter.len());
+            Ok(out)
+        }
+    }
+
+    /// Advances self forward to the end, returning the advanced over portion.
+    fn advance_to_end(&'a mut self) -> Self::AdvanceOut {
+        let amount = self.len();
+        // Safety: amount is the length of self
+        unsafe { self.advance_unchecked(amount) }
+    }
+}
+
+impl<'a, T, A> AdvanceSearch<'a, T> for A where A: Advance<'a, Element = T> + Deref<Target = [T]> {}
@@ -0,0 +1,213 @@
+#[cfg(feature = "alloc")]
+use crate::Advance;
+use crate::{not_enough_data, AdvanceArray, AdvanceError, Length};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, vec::Vec};
+use core::ops::Deref;
+
+/// Advances over data split across several slices as if it were one
+///
+/// Portions within a single segment are borrowed, portions crossing segments are copied into
+/// caller provided scratch space, an array, or a `Cow` with the `alloc` feature.
+#[derive(Debug)]
+pub struct Segments<'b, T> {
+    current: &'b [T],
+    next: &'b [T],
+    segments: &'b [&'b [T]],
+    len: usize,
+}
+
+impl<'b, T> Segments<'b, T> {
+    /// Creates an advancer over `segments` in order
+    pub fn new(segments: &'b [&'b [T]]) -> Self {
+        Self {
+            current: &[],
+            next: &[],
+            segments,
+            len: segments.iter().map(|segment| segment.len()).sum(),
+        }
+    }
+
+    /// Creates an advancer over `first` then `second`, such as the halves from `VecDeque::as_slices`
+    pub fn from_pair(first: &'b [T], second: &'b [T]) -> Self {
+        Self {
+            current: first,
+            next: second,
+            segments: &[],
+            len: first.len() + second.len(),
+        }
+    }
+
+    /// Moves on to the next non-empty segment if the current one is used up.
+    fn next_segment(&mut self) {
+        while self.current.is_empty() {
+            if !self.next.is_empty() {
+                self.current = core::mem::take(&mut self.next);
+            } else if let Some((first, rest)) = self.segments.split_first() {
+                self.current = first;
+                self.segments = rest;
+            } else {
+                break;
+            }
+        }
+    }
+
+    /// Advances within the current segment if it holds `amount` elements.
+    fn advance_contiguous(&mut self, amount: usize) -> Option<&'b [T]> {
+        self.next_segment();
+        if self.current.len() < amount {
+            return None;
+        }
+        let (out, rest) = self.current.split_at(amount);
+        self.current = rest;
+        self.len -= amount;
+        Some(out)
+    }
+
+    /// Advances over `amount` elements segment by segment, passing each piece to `f`.
+    /// Caller must check that `amount` is not greater than the length of self.
+    fn advance_pieces(&mut self, mut amount: usize, mut f: impl FnMut(&'b [T])) {
+        self.len -= amount;
+        while amount > 0 {
+            self.next_segment();
+            let (piece, rest) = self.current.split_at(amount.min(self.current.len()));
+            self.current = rest;
+            amount -= piece.len();
+            f(piece);
+        }
+    }
+
+    /// Advances self forward by `amount`, returning the advanced over portion.
+    /// It is borrowed if within one segment, otherwise copied into the front of `scratch`.
+    /// Panics if not enough data or `scratch` is too small.
+    pub fn advance_scratch<'s>(&mut self, amount: usize, scratch: &'s mut [T]) -> &'s [T]
+    where
+        'b: 's,
+        T: Copy,
+    {
+        match self.try_advance_scratch(amount, scratch) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward by `amount`, returning the advanced over portion.
+    /// It is borrowed if within one segment, otherwise copied into the front of `scratch`.
+    /// Errors if not enough data or `scratch` is too small, nothing is advanced over on error.
+    pub fn try_advance_scratch<'s>(
+        &mut self,
+        amount: usize,
+        scratch: &'s mut [T],
+    ) -> Result<&'s [T], AdvanceError>
+    where
+        'b: 's,
+        T: Copy,
+    {
+        if self.len < amount {
+            return Err(not_enough_data(self, amount));
+        }
+        if let Some(out) = self.advance_contiguous(amount) {
+            return Ok(out);
+        }
+        let capacity = scratch.len();
+        let scratch = scratch
+            .get_mut(..amount)
+            .ok_or(AdvanceError::ScratchTooSmall {
+                needed: amount,
+                capacity,
+            })?;
+        let mut offset = 0;
+        self.advance_pieces(amount, |piece| {
+            scratch[offset..offset + piece.len()].copy_from_slice(piece);
+            offset += piece.len();
+        });
+        Ok(scratch)
+    }
+}
+
+impl<'b, T> From<&'b [&'b [T]]> for Segments<'b, T> {
+    fn from(segments: &'b [&'b [T]]) -> Self {
+        Self::new(segments)
+    }
+}
+
+impl<'b, T> From<(&'b [T], &'b [T])> for Segments<'b, T> {
+    fn from((first, second): (&'b [T], &'b [T])) -> Self {
+        Self::from_pair(first, second)
+    }
+}
+
+impl<T> Clone for Segments<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Segments<'_, T> {}
+
+impl<T> Length for Segments<'_, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+/// Borrowed if within one segment, otherwise cloned.
+#[cfg(feature = "alloc")]
+impl<'b, T: Clone> Advance<'_> for Segments<'b, T> {
+    type Element = T;
+    type AdvanceOut = Cow<'b, [T]>;
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        if let Some(out) = self.advance_contiguous(amount) {
+            return Cow::Borrowed(out);
+        }
+        let mut out = Vec::with_capacity(amount);
+        self.advance_pieces(amount, |piece| out.extend_from_slice(piece));
+        Cow::Owned(out)
+    }
+}
+
+impl<'a, 'b, T: Copy> AdvanceArray<'a> for Segments<'b, T> {
+    type Element = T;
+    type AdvanceOut<const N: usize>
+        = SegmentArray<'b, T, N>
+    where
+        Self: 'a;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&'a mut self) -> Self::AdvanceOut<N> {
+        if let Some(out) = self.advance_contiguous(N) {
+            // Safe conversion because returned array will always be same size as value passed in (`N`)
+            return SegmentArray::Borrowed(&*out.as_ptr().cast::<[T; N]>());
+        }
+        let mut array = [const { core::mem::MaybeUninit::<T>::uninit() }; N];
+        let mut offset = 0;
+        self.advance_pieces(N, |piece| {
+            for (slot, element) in array[offset..].iter_mut().zip(piece) {
+                slot.write(*element);
+            }
+            offset += piece.len();
+        });
+        // Safety: Caller guarantees `N` elements were advanced over, initializing every element
+        SegmentArray::Owned(array.as_ptr().cast::<[T; N]>().read())
+    }
+}
+
+/// An array advanced over from [`Segments`], borrowed if within one segment and copied otherwise
+#[derive(Copy, Clone, Debug)]
+pub enum SegmentArray<'b, T, const N: usize> {
+    /// Borrowed from a single segment
+    Borrowed(&'b [T; N]),
+    /// Copied from across segments
+    Owned([T; N]),
+}
+
+impl<T, const N: usize> Deref for SegmentArray<'_, T, N> {
+    type Target = [T; N];
+
+    fn deref(&self) -> &Self::Target {
+        match self {
+            Self::Borrowed(array) => array,
+            Self::Owned(array) => array,
+        }
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{Advance, AdvanceArray, Length};
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::{Bound, Deref, RangeBounds};
+
+/// A reference counted byte buffer, advancing hands out handles into the same allocation
+///
+/// Cloning, slicing and advancing never copy the bytes.
+#[derive(Clone)]
+pub struct SharedBytes {
+    data: Arc<[u8]>,
+    start: usize,
+    end: usize,
+}
+
+impl SharedBytes {
+    /// Creates a handle to all of `data`
+    pub fn new(data: Arc<[u8]>) -> Self {
+        let end = data.len();
+        Self {
+            data,
+            start: 0,
+            end,
+        }
+    }
+
+    /// Gets the bytes of this handle.
+    pub fn as_slice(&self) -> &[u8] {
+        &self.data[self.start..self.end]
+    }
+
+    /// Gets a handle to `range` of this handle's bytes.
+    /// Panics if `range` is out of bounds.
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(start) => *start,
+            Bound::Excluded(start) => start.checked_add(1).expect("range start overflows"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => end.checked_add(1).expect("range end overflows"),
+            Bound::Excluded(end) => *end,
+            Bound::Unbounded => self.len(),
+        };
+        assert!(
+            start <= end && end <= self.len(),
+            "range `{start}..{end}` out of bounds for length `{}`",
+            self.len()
+        );
+        Self {
+            data: self.data.clone(),
+            start: self.start + start,
+            end: self.start + end,
+        }
+    }
+}
+
+impl From<Arc<[u8]>> for SharedBytes {
+    fn from(data: Arc<[u8]>) -> Self {
+        Self::new(data)
+    }
+}
+
+impl From<Box<[u8]>> for SharedBytes {
+    fn from(data: Box<[u8]>) -> Self {
+        Self::new(data.into())
+    }
+}
+
+impl From<Vec<u8>> for SharedBytes {
+    fn from(data: Vec<u8>) -> Self {
+        Self::new(data.into())
+    }
+}
+
+/// Copies `data` into a new allocation.
+impl From<&[u8]> for SharedBytes {
+    fn from(data: &[u8]) -> Self {
+        Self::new(data.into())
+    }
+}
+
+impl Deref for SharedBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl AsRef<[u8]> for SharedBytes {
+    fn as_ref(&self) -> &[u8] {
+        self.as_slice()
+    }
+}
+
+impl Debug for SharedBytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_slice(), f)
+    }
+}
+
+impl PartialEq for SharedBytes {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl Eq for SharedBytes {}
+
+impl Hash for SharedBytes {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl Length for SharedBytes {
+    fn len(&self) -> usize {
+        self.end - self.start
+    }
+}
+
+impl Advance<'_> for SharedBytes {
+    type Element = u8;
+    type AdvanceOut = SharedBytes;
+
+    unsafe fn advance_unchecked(&mut self, amount: usize) -> Self::AdvanceOut {
+        let start = self.start;
+        self.start += amount;
+        Self {
+            data: self.data.clone(),
+            start,
+            end: self.start,
+        }
+    }
+}
+
+impl AdvanceArray<'_> for SharedBytes {
+    type Element = u8;
+    type AdvanceOut<const N: usize> = SharedArray<N>;
+
+    unsafe fn advance_array_unchecked<const N: usize>(&mut self) -> Self::AdvanceOut<N> {
+        SharedArray {
+            // Safety: Same requirements as this function
+            bytes: self.advance_unchecked(N),
+        }
+    }
+}
+
+/// A [`SharedBytes`] handle of exactly `N` bytes
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct SharedArray<const N: usize> {
+    bytes: SharedBytes,
+}
+
+impl<const N: usize> SharedArray<N> {
+    /// Converts into a handle of the same bytes.
+    pub fn into_bytes(self) -> SharedBytes {
+        self.bytes
+    }
+}
+
+impl<const N: usize> Deref for SharedArray<N> {
+    type Target = [u8; N];
+
+    fn deref(&self) -> &Self::Target {
+        // Safety: A shared array always covers exactly `N` bytes
+        unsafe { &*self.bytes.as_ptr().cast::<[u8; N]>() }
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for SharedArray<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+}
+
+impl<const N: usize> Debug for SharedArray<N> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Debug::fmt(&self.bytes, f)
+    }
+}
@@ -0,0 +1,183 @@
+use crate::{Advance, AdvanceError};
+
+/// Tuples of arrays that [`AdvanceSplit::advance_split`] can split off in one go, e.g. `([u8; 4], [u8; 8])`
+pub trait SplitArrays<T> {
+    /// Total number of elements in all arrays
+    const LEN: usize;
+
+    /// A tuple of a shared reference to each array
+    type Ref<'b>
+    where
+        T: 'b;
+
+    /// A tuple of a mutable reference to each array
+    type Mut<'b>
+    where
+        T: 'b;
+
+    /// Splits the arrays off of `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must point to `LEN` elements valid for reads for `'b`.
+    unsafe fn split_ref<'b>(ptr: *const T) -> Self::Ref<'b>;
+
+    /// Splits the arrays off of `ptr`.
+    ///
+    /// # Safety
+    /// `ptr` must point to `LEN` elements valid for reads and writes and not otherwise borrowed for `'b`.
+    unsafe fn split_mut<'b>(ptr: *mut T) -> Self::Mut<'b>;
+}
+
+macro_rules! impl_split_arrays {
+    ($($n:ident),*) => {
+        impl<T, $(const $n: usize),*> SplitArrays<T> for ($([T; $n],)*) {
+            const LEN: usize = 0 $(+ $n)*;
+
+            type Ref<'b> = ($(&'b [T; $n],)*) where T: 'b;
+            type Mut<'b> = ($(&'b mut [T; $n],)*) where T: 'b;
+
+            #[allow(unused_assignments)]
+            unsafe fn split_ref<'b>(ptr: *const T) -> Self::Ref<'b> {
+                let mut offset = 0;
+                ($({
+                    // Safety: Arrays are within the `LEN` elements at `ptr` and do not overlap
+                    let array = &*ptr.add(offset).cast::<[T; $n]>();
+                    offset += $n;
+                    array
+                },)*)
+            }
+
+            #[allow(unused_assignments)]
+            unsafe fn split_mut<'b>(ptr: *mut T) -> Self::Mut<'b> {
+                let mut offset = 0;
+                ($({
+                    // Safety: Arrays are within the `LEN` elements at `ptr` and do not overlap
+                    let array = &mut *ptr.add(offset).cast::<[T; $n]>();
+                    offset += $n;
+                    array
+                },)*)
+            }
+        }
+    };
+}
+
+impl_split_arrays!(A);
+impl_split_arrays!(A, B);
+impl_split_arrays!(A, B, C);
+impl_split_arrays!(A, B, C, D);
+impl_split_arrays!(A, B, C, D, E);
+impl_split_arrays!(A, B, C, D, E, F);
+impl_split_arrays!(A, B, C, D, E, F, G);
+impl_split_arrays!(A, B, C, D, E, F, G, H);
+
+/// Advances a slice over several disjoint regions at once, with a single bounds check for the group
+pub trait AdvanceSplit<'b>: Sized {
+    /// The element type of the slice
+    type Element: 'b;
+
+    /// The type each region is returned as
+    type Slice;
+
+    /// The type the arrays of `S` are returned as
+    type Split<S: SplitArrays<Self::Element>>;
+
+    /// Advances self forward by each of `amounts` in turn, returning each advanced over portion.
+    /// Errors if not enough data for all of them, nothing is advanced over on error.
+    fn try_advance_many<const K: usize>(
+        &mut self,
+        amounts: [usize; K],
+    ) -> Result<[Self::Slice; K], AdvanceError>;
+
+    /// Advances self forward by each array of `S` in turn, returning each advanced over portion.
+    /// Errors if not enough data for all of them, nothing is advanced over on error.
+    fn try_advance_split<S: SplitArrays<Self::Element>>(
+        &mut self,
+    ) -> Result<Self::Split<S>, AdvanceError>;
+
+    /// Advances self forward by each of `amounts` in turn, returning each advanced over portion.
+    /// Panics if not enough data for all of them.
+    fn advance_many<const K: usize>(&mut self, amounts: [usize; K]) -> [Self::Slice; K] {
+        match self.try_advance_many(amounts) {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward by each array of `S` in turn, returning each advanced over portion.
+    /// Panics if not enough data for all of them.
+    fn advance_split<S: SplitArrays<Self::Element>>(&mut self) -> Self::Split<S> {
+        match self.try_advance_split::<S>() {
+            Ok(out) => out,
+            Err(error) => panic!("{}", error),
+        }
+    }
+}
+
+/// Checks that `len` covers the sum of `amounts`.
+/// A sum that overflows is never covered, even by the `usize::MAX` length of a zero sized slice.
+fn check_many(len: usize, amounts: &[usize]) -> Result<(), AdvanceError> {
+    let needed = amounts
+        .iter()
+        .try_fold(0usize, |total, amount| total.checked_add(*amount));
+    match needed {
+        Some(needed) if needed <= len => Ok(()),
+        needed => Err(AdvanceError::NotEnoughData {
+            needed: needed.unwrap_or(usize::MAX),
+            remaining: len,
+        }),
+    }
+}
+
+/// Checks that `len` covers `S`.
+fn check_split<T, S: SplitArrays<T>>(len: usize) -> Result<(), AdvanceError> {
+    if S::LEN > len {
+        Err(AdvanceError::NotEnoughData {
+            needed: S::LEN,
+            remaining: len,
+        })
+    } else {
+        Ok(())
+    }
+}
+
+impl<'b, T> AdvanceSplit<'b> for &'b mut [T] {
+    type Element = T;
+    type Slice = &'b mut [T];
+    type Split<S: SplitArrays<T>> = S::Mut<'b>;
+
+    fn try_advance_many<const K: usize>(
+        &mut self,
+        amounts: [usize; K],
+    ) -> Result<[Self::Slice; K], AdvanceError> {
+        check_many(self.len(), &amounts)?;
+        // Safety: The sum of amounts is not greater than the length of self
+        Ok(amounts.map(|amount| unsafe { self.advance_unchecked(amount) }))
+    }
+
+    fn try_advance_split<S: SplitArrays<T>>(&mut self) -> Result<Self::Split<S>, AdvanceError> {
+        check_split::<T, S>(self.len())?;
+        // Safety: `S::LEN` is not greater than the length of self and the advanced over portion is not borrowed elsewhere
+        Ok(unsafe { S::split_mut(self.advance_unchecked(S::LEN).as_mut_ptr()) })
+    }
+}
+
+impl<'b, T> AdvanceSplit<'b> for &'b [T] {
+    type Element = T;
+    type Slice = &'b [T];
+    type Split<S: SplitArrays<T>> = S::Ref<'b>;
+
+    fn try_advance_many<const K: usize>(
+        &mut self,
+        amounts: [usize; K],
+    ) -> Result<[Self::Slice; K], AdvanceError> {
+        check_many(self.len(), &amounts)?;
+        // Safety: The sum of amounts is not greater than the length of self
+        Ok(amounts.map(|amount| unsafe { self.advance_unchecked(amount) }))
+    }
+
+    fn try_advance_split<S: SplitArrays<T>>(&mut self) -> Result<Self::Split<S>, AdvanceError> {
+        check_split::<T, S>(self.len())?;
+        // Safety: `S::LEN` is not greater than the length of self
+        Ok(unsafe { S::split_ref(self.advance_unchecked(S::LEN).as_ptr()) })
+    }
+}
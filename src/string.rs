@@ -0,0 +1,166 @@
+use crate::prefix::{read_prefix, LengthPrefix};
+use crate::{Advance, AdvanceBytes, AdvanceError, AdvanceWrite, Cursor, Length};
+use core::ops::Deref;
+use core::str::from_utf8;
+
+/// Validates `bytes` as UTF-8, reporting the offset of the first invalid byte.
+fn check_utf8(bytes: &[u8]) -> Result<&str, AdvanceError> {
+    from_utf8(bytes).map_err(|error| AdvanceError::InvalidUtf8 {
+        offset: error.valid_up_to(),
+    })
+}
+
+/// Trims trailing `pad` bytes off `bytes`.
+fn trim_padding(bytes: &[u8], pad: u8) -> &[u8] {
+    let end = bytes
+        .iter()
+        .rposition(|byte| *byte != pad)
+        .map_or(0, |index| index + 1);
+    &bytes[..end]
+}
+
+/// Advances a byte advancer, returning the advanced over portion as UTF-8 strings.
+///
+/// Nothing is advanced over if the string is invalid UTF-8.
+pub trait AdvanceStr<'b>: AdvanceBytes + Deref<Target = [u8]> + Length + Sized {
+    /// Advances self forward by `len` bytes, returning the advanced over portion.
+    /// Errors if not enough data.
+    fn try_advance_bytes(&mut self, len: usize) -> Result<&'b [u8], AdvanceError>;
+
+    /// Advances self forward by `len` bytes, returning the advanced over portion as a `str`.
+    /// Panics if not enough data or the data is invalid UTF-8.
+    fn advance_str(&mut self, len: usize) -> &'b str {
+        match self.try_advance_str(len) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward by `len` bytes, returning the advanced over portion as a `str`.
+    /// Errors if not enough data or the data is invalid UTF-8.
+    fn try_advance_str(&mut self, len: usize) -> Result<&'b str, AdvanceError> {
+        if let Some(bytes) = self.get(..len) {
+            check_utf8(bytes).map_err(|error| self.locate(error))?;
+        }
+        let bytes = self.try_advance_bytes(len)?;
+        // Safety: The same bytes were validated above
+        Ok(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Advances self forward by `N` bytes, returning the advanced over portion as a `str` with trailing `pad` bytes trimmed.
+    /// Panics if not enough data or the data is invalid UTF-8.
+    fn advance_padded_str<const N: usize>(&mut self, pad: u8) -> &'b str {
+        match self.try_advance_padded_str::<N>(pad) {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward by `N` bytes, returning the advanced over portion as a `str` with trailing `pad` bytes trimmed.
+    /// Errors if not enough data or the data is invalid UTF-8.
+    fn try_advance_padded_str<const N: usize>(&mut self, pad: u8) -> Result<&'b str, AdvanceError> {
+        if let Some(bytes) = self.get(..N) {
+            check_utf8(trim_padding(bytes, pad)).map_err(|error| self.locate(error))?;
+        }
+        let bytes = trim_padding(self.try_advance_bytes(N)?, pad);
+        // Safety: The same bytes were validated above
+        Ok(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+
+    /// Advances self forward past the next NUL byte, returning the portion before it as a `str`.
+    /// Panics if there is no NUL byte or the data is invalid UTF-8.
+    fn advance_cstr(&mut self) -> &'b str {
+        match self.try_advance_cstr() {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Advances self forward past the next NUL byte, returning the portion before it as a `str`.
+    /// Errors if there is no NUL byte or the data is invalid UTF-8.
+    fn try_advance_cstr(&mut self) -> Result<&'b str, AdvanceError> {
+        let len = self
+            .iter()
+            .position(|byte| *byte == 0)
+            .ok_or_else(|| self.locate(AdvanceError::MissingNul))?;
+        let value = self.try_advance_str(len)?;
+        self.try_advance_bytes(1)?;
+        Ok(value)
+    }
+
+    /// Reads a `P` length and advances self forward by it, returning the advanced over portion as a `str`.
+    /// Panics if not enough data, the prefix is invalid, or the data is invalid UTF-8.
+    fn advance_len_prefixed_str<P: LengthPrefix>(&mut self) -> &'b str {
+        match self.try_advance_len_prefixed_str::<P>() {
+            Ok(value) => value,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Reads a `P` length and advances self forward by it, returning the advanced over portion as a `str`.
+    /// Errors if not enough data, the prefix is invalid, or the data is invalid UTF-8.
+    fn try_advance_len_prefixed_str<P: LengthPrefix>(&mut self) -> Result<&'b str, AdvanceError> {
+        let (prefix, len) = read_prefix::<P, _>(self, usize::MAX)?;
+        from_utf8(&self[prefix..prefix + len]).map_err(|error| {
+            self.locate(AdvanceError::InvalidUtf8 {
+                offset: prefix + error.valid_up_to(),
+            })
+        })?;
+        let bytes = &self.try_advance_bytes(prefix + len)?[prefix..];
+        // Safety: The same bytes were validated above
+        Ok(unsafe { core::str::from_utf8_unchecked(bytes) })
+    }
+}
+
+impl<'b> AdvanceStr<'b> for &'b [u8] {
+    fn try_advance_bytes(&mut self, len: usize) -> Result<&'b [u8], AdvanceError> {
+        self.try_advance(len)
+    }
+}
+
+impl<'b> AdvanceStr<'b> for &'b mut [u8] {
+    fn try_advance_bytes(&mut self, len: usize) -> Result<&'b [u8], AdvanceError> {
+        self.try_advance(len).map(|bytes| &*bytes)
+    }
+}
+
+impl<'b> AdvanceStr<'b> for Cursor<'b, u8> {
+    fn try_advance_bytes(&mut self, len: usize) -> Result<&'b [u8], AdvanceError> {
+        self.try_advance(len)
+    }
+}
+
+/// Writes strings into the front of a byte advancer
+pub trait AdvanceStrWrite: AdvanceWrite {
+    /// Writes `value` into exactly `N` bytes, filling the rest with `pad`.
+    /// `value` is truncated to the last char boundary that fits if it is longer than `N` bytes.
+    /// Errors if not enough space.
+    fn write_padded_str<const N: usize>(
+        &mut self,
+        value: &str,
+        pad: u8,
+    ) -> Result<(), AdvanceError> {
+        let mut end = value.len().min(N);
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        let out = self.try_advance_write(N)?;
+        out[..end].copy_from_slice(&value.as_bytes()[..end]);
+        out[end..].fill(pad);
+        Ok(())
+    }
+
+    /// Writes `value` followed by a NUL byte.
+    /// Errors if not enough space or `value` contains a NUL byte.
+    fn write_cstr(&mut self, value: &str) -> Result<(), AdvanceError> {
+        if let Some(index) = value.bytes().position(|byte| byte == 0) {
+            return Err(AdvanceError::InteriorNul { index });
+        }
+        let out = self.try_advance_write(value.len() + 1)?;
+        out[..value.len()].copy_from_slice(value.as_bytes());
+        out[value.len()] = 0;
+        Ok(())
+    }
+}
+
+impl<W: AdvanceWrite + ?Sized> AdvanceStrWrite for W {}
@@ -0,0 +1,142 @@
+use crate::{AdvanceBytes, AdvanceError, AdvanceWrite};
+
+macro_rules! read_unsigned {
+    ($($ty:ty => $read:ident, $try_read:ident;)*) => {
+        $(
+            #[doc = concat!("Reads an unsigned LEB128 `", stringify!($ty), "`.")]
+            /// Panics if not enough data or the encoding is not canonical.
+            fn $read(&mut self) -> $ty {
+                match self.$try_read() {
+                    Ok(value) => value,
+                    Err(error) => panic!("{}", error),
+                }
+            }
+
+            #[doc = concat!("Reads an unsigned LEB128 `", stringify!($ty), "`.")]
+            /// Errors if not enough data or the encoding is overlong or overflows.
+            fn $try_read(&mut self) -> Result<$ty, AdvanceError> {
+                const BITS: u32 = <$ty>::BITS;
+                let mut value: $ty = 0;
+                let mut shift = 0;
+                loop {
+                    let byte = self.try_read_u8()?;
+                    let bits = <$ty>::from(byte & 0x7f);
+                    // Only the final byte can overflow, check the bits it sets fit
+                    if BITS - shift < 7 && bits >> (BITS - shift) != 0 {
+                        return Err(AdvanceError::VarintOverflow { bits: BITS });
+                    }
+                    value |= bits << shift;
+                    if byte & 0x80 == 0 {
+                        if byte == 0 && shift > 0 {
+                            return Err(AdvanceError::VarintOverlong);
+                        }
+                        return Ok(value);
+                    }
+                    shift += 7;
+                    if shift >= BITS {
+                        return Err(AdvanceError::VarintOverflow { bits: BITS });
+                    }
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! read_signed {
+    ($($ty:ty => $read:ident, $try_read:ident, $try_read_unsigned:ident;)*) => {
+        $(
+            #[doc = concat!("Reads a zigzag LEB128 `", stringify!($ty), "`.")]
+            /// Panics if not enough data or the encoding is not canonical.
+            fn $read(&mut self) -> $ty {
+                match self.$try_read() {
+                    Ok(value) => value,
+                    Err(error) => panic!("{}", error),
+                }
+            }
+
+            #[doc = concat!("Reads a zigzag LEB128 `", stringify!($ty), "`.")]
+            /// Errors if not enough data or the encoding is overlong or overflows.
+            fn $try_read(&mut self) -> Result<$ty, AdvanceError> {
+                let value = self.$try_read_unsigned()?;
+                Ok((value >> 1) as $ty ^ -((value & 1) as $ty))
+            }
+        )*
+    };
+}
+
+/// Reads LEB128 variable length integers off the front of a byte advancer
+pub trait AdvanceVarint: AdvanceBytes {
+    read_unsigned! {
+        u8 => read_varint_u8, try_read_varint_u8;
+        u16 => read_varint_u16, try_read_varint_u16;
+        u32 => read_varint_u32, try_read_varint_u32;
+        u64 => read_varint_u64, try_read_varint_u64;
+        u128 => read_varint_u128, try_read_varint_u128;
+        usize => read_varint_usize, try_read_varint_usize;
+    }
+
+    read_signed! {
+        i8 => read_varint_i8, try_read_varint_i8, try_read_varint_u8;
+        i16 => read_varint_i16, try_read_varint_i16, try_read_varint_u16;
+        i32 => read_varint_i32, try_read_varint_i32, try_read_varint_u32;
+        i64 => read_varint_i64, try_read_varint_i64, try_read_varint_u64;
+        i128 => read_varint_i128, try_read_varint_i128, try_read_varint_u128;
+        isize => read_varint_isize, try_read_varint_isize, try_read_varint_usize;
+    }
+}
+
+impl<A: AdvanceBytes> AdvanceVarint for A {}
+
+macro_rules! write_unsigned {
+    ($($ty:ty => $write:ident;)*) => {
+        $(
+            #[doc = concat!("Writes an unsigned LEB128 `", stringify!($ty), "`.")]
+            /// Errors if not enough space.
+            fn $write(&mut self, mut value: $ty) -> Result<(), AdvanceError> {
+                loop {
+                    let byte = (value & 0x7f) as u8;
+                    value >>= 7;
+                    if value == 0 {
+                        return self.write_u8(byte);
+                    }
+                    self.write_u8(byte | 0x80)?;
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! write_signed {
+    ($($ty:ty => $write:ident, $write_unsigned:ident, $unsigned:ty;)*) => {
+        $(
+            #[doc = concat!("Writes a zigzag LEB128 `", stringify!($ty), "`.")]
+            /// Errors if not enough space.
+            fn $write(&mut self, value: $ty) -> Result<(), AdvanceError> {
+                self.$write_unsigned(((value << 1) ^ (value >> (<$ty>::BITS - 1))) as $unsigned)
+            }
+        )*
+    };
+}
+
+/// Writes LEB128 variable length integers into the front of a byte advancer
+pub trait AdvanceVarintWrite: AdvanceWrite {
+    write_unsigned! {
+        u8 => write_varint_u8;
+        u16 => write_varint_u16;
+        u32 => write_varint_u32;
+        u64 => write_varint_u64;
+        u128 => write_varint_u128;
+        usize => write_varint_usize;
+    }
+
+    write_signed! {
+        i8 => write_varint_i8, write_varint_u8, u8;
+        i16 => write_varint_i16, write_varint_u16, u16;
+        i32 => write_varint_i32, write_varint_u32, u32;
+        i64 => write_varint_i64, write_varint_u64, u64;
+        i128 => write_varint_i128, write_varint_u128, u128;
+        isize => write_varint_isize, write_varint_usize, usize;
+    }
+}
+
+impl<W: AdvanceWrite + ?Sized> AdvanceVarintWrite for W {}
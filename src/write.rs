@@ -0,0 +1,91 @@
+use crate::{Advance, AdvanceError};
+
+macro_rules! write_fns {
+    ($($ty:ty => $write:ident, $to:ident;)*) => {
+        $(
+            #[doc = concat!("Writes a `", stringify!($ty), "` using `", stringify!($ty), "::", stringify!($to), "`.")]
+            /// Errors if not enough space.
+            fn $write(&mut self, value: $ty) -> Result<(), AdvanceError> {
+                self.write_bytes(&value.$to())
+            }
+        )*
+    };
+}
+
+/// Writes primitives into the front of a byte advancer
+pub trait AdvanceWrite {
+    /// Advances self forward by `amount`, returning the advanced over portion to be written into.
+    /// Errors if not enough space.
+    fn try_advance_write(&mut self, amount: usize) -> Result<&mut [u8], AdvanceError>;
+
+    /// Writes `bytes`, advancing self past them.
+    /// Errors if not enough space.
+    fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), AdvanceError> {
+        self.try_advance_write(bytes.len())?.copy_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Writes `amount` copies of `byte`.
+    /// Named `write_fill` as `[u8]::fill` would shadow `fill` on `&mut [u8]`.
+    /// Errors if not enough space.
+    fn write_fill(&mut self, amount: usize, byte: u8) -> Result<(), AdvanceError> {
+        self.try_advance_write(amount)?.fill(byte);
+        Ok(())
+    }
+
+    /// Writes `amount` zero bytes.
+    /// Errors if not enough space.
+    fn write_zeroed(&mut self, amount: usize) -> Result<(), AdvanceError> {
+        self.write_fill(amount, 0)
+    }
+
+    /// Writes a `bool` as a single `0` or `1` byte.
+    /// Errors if not enough space.
+    fn write_bool(&mut self, value: bool) -> Result<(), AdvanceError> {
+        self.write_u8(value as u8)
+    }
+
+    write_fns! {
+        u8 => write_u8, to_ne_bytes;
+        i8 => write_i8, to_ne_bytes;
+
+        u16 => write_u16_le, to_le_bytes;
+        u16 => write_u16_be, to_be_bytes;
+        u16 => write_u16_ne, to_ne_bytes;
+        u32 => write_u32_le, to_le_bytes;
+        u32 => write_u32_be, to_be_bytes;
+        u32 => write_u32_ne, to_ne_bytes;
+        u64 => write_u64_le, to_le_bytes;
+        u64 => write_u64_be, to_be_bytes;
+        u64 => write_u64_ne, to_ne_bytes;
+        u128 => write_u128_le, to_le_bytes;
+        u128 => write_u128_be, to_be_bytes;
+        u128 => write_u128_ne, to_ne_bytes;
+
+        i16 => write_i16_le, to_le_bytes;
+        i16 => write_i16_be, to_be_bytes;
+        i16 => write_i16_ne, to_ne_bytes;
+        i32 => write_i32_le, to_le_bytes;
+        i32 => write_i32_be, to_be_bytes;
+        i32 => write_i32_ne, to_ne_bytes;
+        i64 => write_i64_le, to_le_bytes;
+        i64 => write_i64_be, to_be_bytes;
+        i64 => write_i64_ne, to_ne_bytes;
+        i128 => write_i128_le, to_le_bytes;
+        i128 => write_i128_be, to_be_bytes;
+        i128 => write_i128_ne, to_ne_bytes;
+
+        f32 => write_f32_le, to_le_bytes;
+        f32 => write_f32_be, to_be_bytes;
+        f32 => write_f32_ne, to_ne_bytes;
+        f64 => write_f64_le, to_le_bytes;
+        f64 => write_f64_be, to_be_bytes;
+        f64 => write_f64_ne, to_ne_bytes;
+    }
+}
+
+impl AdvanceWrite for &'_ mut [u8] {
+    fn try_advance_write(&mut self, amount: usize) -> Result<&mut [u8], AdvanceError> {
+        self.try_advance(amount)
+    }
+}
@@ -0,0 +1,125 @@
+//! Alignment-aware advancing with padding checks.
+
+use advancer::{Advance, AdvanceAlign, AdvanceError, Cursor, CursorMut, SeekFrom};
+
+#[repr(C, align(16))]
+struct Aligned([u8; 32]);
+
+#[test]
+fn padding() {
+    let buffer = Aligned([0; 32]);
+    let data = &buffer.0[3..];
+    assert_eq!(data.padding_to(1).unwrap(), 0);
+    assert_eq!(data.padding_to(4).unwrap(), 1);
+    assert_eq!(data.padding_to(16).unwrap(), 13);
+    assert!(matches!(
+        data.padding_to(6),
+        Err(AdvanceError::InvalidAlignment { align: 6 })
+    ));
+    assert!(matches!(
+        data.padding_to(0),
+        Err(AdvanceError::InvalidAlignment { align: 0 })
+    ));
+}
+
+#[test]
+fn align_forward() {
+    let mut buffer = Aligned([0xaa; 32]);
+    let mut data = &mut buffer.0[1..];
+    data.align_forward(8);
+    assert_eq!(data.len(), 24);
+    data.align_forward(8);
+    assert_eq!(data.len(), 24);
+    let out = data.advance_aligned(2, 4);
+    assert_eq!(out, &[0xaa, 0xaa]);
+    let out = data.advance_aligned(4, 4);
+    assert_eq!(out.as_ptr() as usize % 4, 0);
+    assert_eq!(data.len(), 16);
+}
+
+#[test]
+fn strict() {
+    let mut buffer = Aligned([0; 32]);
+    buffer.0[2] = 5;
+    let mut data = &buffer.0[1..];
+    assert!(matches!(
+        data.try_align_forward_strict(4),
+        Err(AdvanceError::NonZeroPadding { index: 1, value: 5 })
+    ));
+    assert!(matches!(
+        data.try_advance_aligned_strict(1, 4),
+        Err(AdvanceError::NonZeroPadding { index: 1, value: 5 })
+    ));
+    assert_eq!(data.len(), 31);
+    data.align_forward(4);
+    assert_eq!(data.len(), 28);
+
+    let mut data = &buffer.0[5..];
+    assert_eq!(data.advance_aligned_strict(4, 8), &[0; 4]);
+    assert_eq!(data.len(), 20);
+}
+
+#[test]
+fn not_enough_data() {
+    let buffer = Aligned([0; 32]);
+    let mut data = &buffer.0[1..6];
+    assert!(matches!(
+        data.try_advance_aligned(3, 4),
+        Err(AdvanceError::NotEnoughData {
+            needed: 6,
+            remaining: 5
+        })
+    ));
+    assert!(matches!(
+        data.try_advance_aligned_strict(usize::MAX, 4),
+        Err(AdvanceError::NotEnoughData {
+            needed: usize::MAX,
+            remaining: 5
+        })
+    ));
+    assert!(matches!(
+        data.try_align_forward_strict(8),
+        Err(AdvanceError::NotEnoughData {
+            needed: 7,
+            remaining: 5
+        })
+    ));
+    assert_eq!(data.len(), 5);
+    assert_eq!(data.advance_aligned(2, 4), &[0, 0]);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn cursor_offsets() {
+    let bytes = [0u8, 0, 1, 0, 0, 0];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.advance(4);
+    assert!(matches!(
+        cursor.try_advance_aligned(1, 8),
+        Err(AdvanceError::NotEnoughDataAt {
+            needed: 5,
+            remaining: 2,
+            offset: 4,
+            length: 6
+        })
+    ));
+
+    let mut bytes = bytes;
+    let mut cursor = CursorMut::new(&mut bytes[..]);
+    cursor.advance(1);
+    assert!(matches!(
+        cursor.try_align_forward_strict(4),
+        Err(AdvanceError::NonZeroPadding { index: 1, value: 1 })
+    ));
+    cursor.seek(SeekFrom::Start(5)).unwrap();
+    assert!(matches!(
+        cursor.try_align_forward_strict(8),
+        Err(AdvanceError::NotEnoughDataAt {
+            needed: 3,
+            remaining: 1,
+            offset: 5,
+            length: 6
+        })
+    ));
+    assert_eq!(cursor.position(), 5);
+}
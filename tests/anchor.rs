@@ -0,0 +1,100 @@
+//! Anchor discriminators, known answers from `sha256sum`.
+
+use advancer::anchor::{
+    account_discriminator, discriminator, Decoder, Discriminator, Dispatch, ExpectDiscriminator,
+};
+use advancer::{AdvanceBytes, AdvanceError};
+
+const FLEET: Discriminator = account_discriminator("Fleet");
+
+#[test]
+fn discriminators() {
+    assert_eq!(FLEET, [0x6d, 0xcf, 0xfb, 0x30, 0x6a, 0x02, 0x88, 0xa3]);
+    assert_eq!(
+        discriminator("global", "initialize"),
+        [0xaf, 0xaf, 0x6d, 0x1f, 0x0d, 0x98, 0x9b, 0xed]
+    );
+    // 108 bytes, spanning two blocks
+    assert_eq!(
+        account_discriminator(&"x".repeat(100)),
+        [0x80, 0x03, 0xdd, 0x79, 0x9b, 0x2f, 0x39, 0x50]
+    );
+}
+
+#[test]
+fn expect() {
+    let mut data = &[0x6d, 0xcf, 0xfb, 0x30, 0x6a, 0x02, 0x88, 0xa3, 7][..];
+    data.expect_discriminator(&FLEET).unwrap();
+    assert_eq!(data, &[7]);
+
+    let mut data = &[0u8; 9][..];
+    assert!(matches!(
+        data.expect_discriminator(&FLEET),
+        Err(AdvanceError::DiscriminatorMismatch {
+            expected: FLEET,
+            found: [0, 0, 0, 0, 0, 0, 0, 0]
+        })
+    ));
+    assert_eq!(data.len(), 1);
+    assert!(matches!(
+        data.expect_discriminator(&FLEET),
+        Err(AdvanceError::NotEnoughData {
+            needed: 8,
+            remaining: 1
+        })
+    ));
+}
+
+#[derive(Debug, PartialEq)]
+enum Account {
+    Fleet(u8),
+    Ship(u16),
+}
+
+const SHIP: Discriminator = account_discriminator("Ship");
+
+fn fleet(data: &mut &[u8]) -> Result<Account, AdvanceError> {
+    data.try_read_u8().map(Account::Fleet)
+}
+
+fn ship(data: &mut &[u8]) -> Result<Account, AdvanceError> {
+    data.try_read_u16_le().map(Account::Ship)
+}
+
+const ACCOUNTS: Dispatch<'static, 'static, Account> = Dispatch::new(&[
+    (FLEET, fleet as Decoder<Account>),
+    (SHIP, ship as Decoder<Account>),
+]);
+
+/// Builds account data from its discriminator and body, borrowable for as long as the const table.
+fn account(discriminator: Discriminator, body: &[u8]) -> &'static [u8] {
+    [&discriminator[..], body].concat().leak()
+}
+
+#[test]
+fn dispatch() {
+    let mut data = account(SHIP, &[1, 2, 3]);
+    assert_eq!(ACCOUNTS.decode(&mut data).unwrap(), Account::Ship(0x0201));
+    assert_eq!(data, &[3]);
+
+    assert_eq!(
+        ACCOUNTS.decode(&mut account(FLEET, &[9])).unwrap(),
+        Account::Fleet(9)
+    );
+    assert!(ACCOUNTS.get(&[0; 8]).is_none());
+
+    let mut data = &[1u8, 2, 3, 4, 5, 6, 7, 8, 9][..];
+    assert!(matches!(
+        ACCOUNTS.decode(&mut data),
+        Err(AdvanceError::UnknownDiscriminator {
+            found: [1, 2, 3, 4, 5, 6, 7, 8]
+        })
+    ));
+    assert!(matches!(
+        ACCOUNTS.decode(&mut account(SHIP, &[1])),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+}
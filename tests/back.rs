@@ -0,0 +1,64 @@
+//! Advancing from the back and by single elements.
+
+use advancer::{Advance, AdvanceBack, AdvanceBackArray, AdvanceError, AdvanceOne};
+
+#[test]
+fn back() {
+    let bytes = [1u8, 2, 3, 4, 5, 6];
+    let mut data = &bytes[..];
+    assert_eq!(data.advance_back(2), &[5, 6]);
+    assert_eq!(data.advance_back_array::<1>(), &[4]);
+    assert_eq!(data.advance(1), &[1]);
+    assert_eq!(data, &[2, 3]);
+    assert!(matches!(
+        data.try_advance_back(3),
+        Err(AdvanceError::NotEnoughData {
+            needed: 3,
+            remaining: 2
+        })
+    ));
+    assert!(data.try_advance_back_array::<3>().is_err());
+    assert_eq!(data.advance_back(2), &[2, 3]);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn back_mut() {
+    let mut bytes = [1u8, 2, 3, 4];
+    let mut data = &mut bytes[..];
+    let back = data.advance_back_array::<2>();
+    let front = data.advance(2);
+    back.swap(0, 1);
+    front[0] = 9;
+    assert!(data.is_empty());
+    assert_eq!(bytes, [9, 2, 4, 3]);
+}
+
+#[test]
+fn one() {
+    let mut bytes = [1u8, 2, 3];
+    let mut data = &mut bytes[..];
+    *data.advance_one() += 10;
+    *data.advance_back_one() += 20;
+    assert_eq!(*data.advance_one(), 2);
+    assert!(matches!(
+        data.try_advance_one(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 1,
+            remaining: 0
+        })
+    ));
+    assert!(data.try_advance_back_one().is_err());
+    assert_eq!(bytes, [11, 2, 23]);
+
+    let mut data = &[7u16][..];
+    assert_eq!(*data.advance_back_one(), 7);
+    assert!(data.is_empty());
+}
+
+#[test]
+#[should_panic]
+fn back_panics() {
+    let mut data = &[1u8][..];
+    data.advance_back(2);
+}
@@ -0,0 +1,157 @@
+//! Advancing a bit at a time.
+
+use advancer::{AdvanceError, BitAdvancer, BitOrder};
+
+#[test]
+fn msb_first() {
+    let data = [0b1011_0010, 0b1111_0000];
+    let mut bits = BitAdvancer::new(&data[..], BitOrder::MsbFirst);
+    assert!(bits.read_bit());
+    assert_eq!(bits.read_bits(3), 0b011);
+    assert_eq!(bits.read_bits(0), 0);
+    // Crosses into the second byte, big endian across bytes
+    assert_eq!(bits.read_bits(6), 0b00_1011);
+    assert_eq!(bits.bit_position(), 10);
+    assert_eq!(bits.remaining_bits(), 6);
+    assert_eq!(bits.read_signed_bits(3), -2);
+}
+
+#[test]
+fn lsb_first() {
+    let data = [0b1011_0010, 0b1111_0001];
+    let mut bits = BitAdvancer::new(&data[..], BitOrder::LsbFirst);
+    assert!(!bits.read_bit());
+    assert_eq!(bits.read_bits(3), 0b001);
+    // Crosses into the second byte, little endian across bytes
+    assert_eq!(bits.read_bits(6), 0b01_1011);
+    assert_eq!(bits.read_signed_bits(6), -4);
+    assert_eq!(bits.order(), BitOrder::LsbFirst);
+}
+
+#[test]
+fn round_trip() {
+    let fields: [(u32, u64); 9] = [
+        (1, 1),
+        (3, 0b101),
+        (0, 0),
+        (7, 0x55),
+        (13, 0x1abc),
+        (64, 0xfedc_ba98_7654_3210),
+        (64, u64::MAX),
+        (33, 0x1_2345_6789),
+        (5, 0b10001),
+    ];
+    for order in [BitOrder::MsbFirst, BitOrder::LsbFirst] {
+        for start in 0..8 {
+            let mut buffer = [0xa5u8; 48];
+            let mut writer = BitAdvancer::new(&mut buffer[..], order);
+            writer.write_bits(start, 0).unwrap();
+            for (bits, value) in fields {
+                // Bits above the width are ignored
+                writer
+                    .write_bits(bits, value | !0 << bits.min(63) << (bits / 64))
+                    .unwrap();
+            }
+            writer.write_signed_bits(12, -1000).unwrap();
+            writer.write_signed_bits(64, i64::MIN).unwrap();
+            let end = writer.bit_position();
+
+            let mut reader = BitAdvancer::new(&buffer[..], order);
+            assert_eq!(reader.read_bits(start), 0);
+            for (bits, value) in fields {
+                assert_eq!(reader.read_bits(bits), value, "{order:?}, {bits} bits");
+            }
+            assert_eq!(reader.read_signed_bits(12), -1000);
+            assert_eq!(reader.read_signed_bits(64), i64::MIN);
+            assert_eq!(reader.bit_position(), end);
+            // Bits after the written ones are untouched
+            let rest = 8 - end as u32 % 8;
+            let expected = match order {
+                BitOrder::MsbFirst => 0xa5 & (u8::MAX >> (8 - rest)),
+                BitOrder::LsbFirst => (0xa5 >> (8 - rest)) as u8,
+            };
+            assert_eq!(reader.read_bits(rest), u64::from(expected));
+            assert!(reader.remaining_bytes().iter().all(|byte| *byte == 0xa5));
+        }
+    }
+}
+
+#[test]
+fn signed() {
+    let mut buffer = [0u8; 4];
+    let mut writer = BitAdvancer::new(&mut buffer[..], BitOrder::MsbFirst);
+    writer.write_signed_bits(4, -8).unwrap();
+    writer.write_signed_bits(4, 7).unwrap();
+    writer.write_signed_bits(1, -1).unwrap();
+    writer.write_signed_bits(0, -1).unwrap();
+    assert_eq!(buffer[0], 0x87);
+
+    let mut reader = BitAdvancer::new(&buffer[..], BitOrder::MsbFirst);
+    assert_eq!(reader.read_signed_bits(4), -8);
+    assert_eq!(reader.read_signed_bits(4), 7);
+    assert_eq!(reader.read_signed_bits(1), -1);
+    assert_eq!(reader.read_signed_bits(0), 0);
+}
+
+#[test]
+fn not_enough_bits() {
+    let mut buffer = [0xffu8; 2];
+    let mut writer = BitAdvancer::new(&mut buffer[..], BitOrder::MsbFirst);
+    writer.write_bits(10, 0).unwrap();
+    assert!(matches!(
+        writer.write_bits(7, 0),
+        Err(AdvanceError::NotEnoughBits {
+            needed: 7,
+            remaining: 6
+        })
+    ));
+    assert_eq!(writer.bit_position(), 10);
+    assert!(writer.write_bit(false).is_ok());
+    assert_eq!(buffer, [0, 0b0001_1111]);
+
+    let mut reader = BitAdvancer::new(&buffer[..], BitOrder::LsbFirst);
+    reader.read_bits(12);
+    assert!(reader.try_read_bits(5).is_err());
+    assert!(reader.try_read_signed_bits(5).is_err());
+    assert_eq!(reader.bit_position(), 12);
+    assert_eq!(reader.read_bits(4), 0b0001);
+    assert!(matches!(
+        reader.try_read_bit(),
+        Err(AdvanceError::NotEnoughBits {
+            needed: 1,
+            remaining: 0
+        })
+    ));
+}
+
+#[test]
+fn align_to_byte() {
+    let mut buffer = [1u8, 2, 3, 4];
+    let mut bits = BitAdvancer::new(&mut buffer[..], BitOrder::MsbFirst);
+    bits.align_to_byte();
+    assert_eq!(bits.bit_position(), 0);
+    assert_eq!(bits.remaining_bytes(), [1, 2, 3, 4]);
+    bits.read_bits(1);
+    // The partially read byte is not included
+    assert_eq!(bits.remaining_bytes(), [2, 3, 4]);
+    bits.align_to_byte();
+    assert_eq!(bits.bit_position(), 8);
+    bits.align_to_byte();
+    assert_eq!(bits.bit_position(), 8);
+    bits.remaining_bytes_mut()[0] = 20;
+    bits.read_bits(16);
+    assert_eq!(bits.into_remaining_bytes(), [4]);
+    assert_eq!(buffer, [1, 20, 3, 4]);
+
+    let mut bits = BitAdvancer::new(&buffer[..], BitOrder::LsbFirst);
+    bits.read_bits(31);
+    bits.align_to_byte();
+    assert_eq!(bits.remaining_bits(), 0);
+    assert!(bits.into_remaining_bytes().is_empty());
+}
+
+#[test]
+#[should_panic(expected = "cannot advance more than 64 bits at once")]
+fn too_wide() {
+    BitAdvancer::new(&[0u8; 16][..], BitOrder::MsbFirst).read_bits(65);
+}
@@ -0,0 +1,167 @@
+//! Golden vectors from the borsh specification.
+
+use advancer::borsh::{from_slice, to_slice, BorshDecode, BorshEncode};
+use advancer::AdvanceError;
+use core::fmt::Debug;
+
+/// Checks `value` serializes to the spec's `bytes` through `to_slice`, and that `from_slice`,
+/// which rejects trailing data, reads those exact bytes back as `value`.
+fn golden<'a, T>(value: T, bytes: &'a [u8])
+where
+    T: BorshDecode<'a> + BorshEncode + PartialEq + Debug,
+{
+    let mut buffer = [0u8; 64];
+    let written = to_slice(&value, &mut buffer).unwrap();
+    assert_eq!(&buffer[..written], bytes);
+    assert_eq!(from_slice::<T>(bytes).unwrap(), value);
+}
+
+#[test]
+fn primitives() {
+    golden(0x12u8, &[0x12]);
+    golden(-2i16, &[0xfe, 0xff]);
+    golden(0x01020304u32, &[4, 3, 2, 1]);
+    golden(u64::MAX, &[0xff; 8]);
+    golden(1u128, &[1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    golden(-1i128, &[0xff; 16]);
+    golden(1.0f32, &[0, 0, 0x80, 0x3f]);
+    golden(-0.5f64, &[0, 0, 0, 0, 0, 0, 0xe0, 0xbf]);
+    golden(true, &[1]);
+    golden(false, &[0]);
+    golden((), &[]);
+}
+
+#[test]
+fn compound() {
+    golden([1u16, 2], &[1, 0, 2, 0]);
+    golden((1u8, 2u16), &[1, 2, 0]);
+    golden(None::<u8>, &[0]);
+    golden(Some(5u8), &[1, 5]);
+    golden(Ok::<u8, u16>(7), &[1, 7]);
+    golden(Err::<u8, u16>(3), &[0, 3, 0]);
+}
+
+#[test]
+fn zero_copy() {
+    golden(&[1u8, 2, 3][..], &[3, 0, 0, 0, 1, 2, 3]);
+    golden("hi", &[2, 0, 0, 0, b'h', b'i']);
+    golden("", &[0, 0, 0, 0]);
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        from_slice::<bool>(&[2]),
+        Err(AdvanceError::InvalidBool { value: 2 })
+    ));
+    assert!(matches!(
+        from_slice::<Option<u8>>(&[2, 0]),
+        Err(AdvanceError::InvalidTag { tag: 2 })
+    ));
+    assert!(matches!(
+        from_slice::<f32>(&f32::NAN.to_le_bytes()),
+        Err(AdvanceError::NaN)
+    ));
+    assert!(matches!(
+        from_slice::<&str>(&[2, 0, 0, 0, 0xc3, 0x28]),
+        Err(AdvanceError::InvalidUtf8 { offset: 0 })
+    ));
+    assert!(matches!(
+        from_slice::<&[u8]>(&[4, 0, 0, 0, 1]),
+        Err(AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 1
+        })
+    ));
+    assert!(matches!(
+        from_slice::<u8>(&[1, 2]),
+        Err(AdvanceError::TrailingData { remaining: 1 })
+    ));
+    assert!(matches!(
+        from_slice::<[u16; 3]>(&[1, 0, 2, 0, 3]),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+}
+
+#[test]
+fn zero_sized() {
+    golden([(); 3], &[]);
+    let mut buffer = [0u8; 8];
+    assert!(matches!(
+        to_slice(&[(); 2][..], &mut buffer),
+        Err(AdvanceError::ZeroSizedElements)
+    ));
+}
+
+#[cfg(feature = "std")]
+mod owned {
+    use super::golden;
+    use advancer::borsh::{from_slice, to_slice};
+    use advancer::AdvanceError;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+    #[test]
+    fn collections() {
+        golden(vec![1u16, 2], &[2, 0, 0, 0, 1, 0, 2, 0]);
+        golden(String::from("abc"), &[3, 0, 0, 0, b'a', b'b', b'c']);
+        golden(Box::new(9u8), &[9]);
+        golden(
+            HashMap::from([(2u8, 20u8), (1, 10)]),
+            &[2, 0, 0, 0, 1, 10, 2, 20],
+        );
+        golden(
+            BTreeMap::from([(2u8, 20u8), (1, 10)]),
+            &[2, 0, 0, 0, 1, 10, 2, 20],
+        );
+        golden(HashSet::from([3u8, 1]), &[2, 0, 0, 0, 1, 3]);
+        golden(BTreeSet::from([3u8, 1]), &[2, 0, 0, 0, 1, 3]);
+    }
+
+    #[test]
+    fn zero_sized() {
+        assert!(matches!(
+            from_slice::<Vec<()>>(&[0xff; 4]),
+            Err(AdvanceError::ZeroSizedElements)
+        ));
+        assert!(matches!(
+            from_slice::<Vec<[u8; 0]>>(&[0; 4]),
+            Err(AdvanceError::ZeroSizedElements)
+        ));
+        assert!(matches!(
+            from_slice::<BTreeMap<(), ()>>(&[1, 0, 0, 0]),
+            Err(AdvanceError::ZeroSizedElements)
+        ));
+        let mut buffer = [0u8; 8];
+        assert!(matches!(
+            to_slice(&HashSet::from([()]), &mut buffer),
+            Err(AdvanceError::ZeroSizedElements)
+        ));
+    }
+
+    #[test]
+    fn partial_array() {
+        golden(
+            [String::from("a"), String::from("b")],
+            &[1, 0, 0, 0, b'a', 1, 0, 0, 0, b'b'],
+        );
+        assert!(matches!(
+            from_slice::<[String; 2]>(&[1, 0, 0, 0, b'a', 1, 0, 0, 0, 0xff]),
+            Err(AdvanceError::InvalidUtf8 { offset: 0 })
+        ));
+    }
+
+    #[test]
+    fn unsorted() {
+        assert!(matches!(
+            from_slice::<HashMap<u8, u8>>(&[2, 0, 0, 0, 2, 20, 1, 10]),
+            Err(AdvanceError::UnsortedKeys { index: 1 })
+        ));
+        assert!(matches!(
+            from_slice::<BTreeSet<u8>>(&[2, 0, 0, 0, 1, 1]),
+            Err(AdvanceError::UnsortedKeys { index: 1 })
+        ));
+    }
+}
@@ -0,0 +1,61 @@
+//! Endian-aware primitive readers.
+
+use advancer::{AdvanceBytes, AdvanceError};
+
+#[test]
+fn endianness() {
+    let bytes = [1u8, 2, 3, 4, 5, 6, 7, 8];
+    assert_eq!((&bytes[..]).read_u16_le(), 0x0201);
+    assert_eq!((&bytes[..]).read_u16_be(), 0x0102);
+    assert_eq!((&bytes[..]).read_u32_le(), 0x04030201);
+    assert_eq!((&bytes[..]).read_u32_be(), 0x01020304);
+    assert_eq!((&bytes[..]).read_u64_le(), 0x0807060504030201);
+    assert_eq!((&bytes[..]).read_u64_be(), 0x0102030405060708);
+    assert_eq!((&bytes[..]).read_u32_ne(), u32::from_ne_bytes([1, 2, 3, 4]));
+}
+
+#[test]
+fn sequential() {
+    let mut bytes = [0u8; 24];
+    bytes[..8].copy_from_slice(&[0xff, 0xfe, 0xff, 0, 0, 0x80, 0x3f, 1]);
+    bytes[8..].copy_from_slice(&i128::MIN.to_le_bytes());
+    let mut data = &bytes[..];
+    assert_eq!(data.read_i8(), -1);
+    assert_eq!(data.read_i16_le(), -2);
+    assert_eq!(data.read_f32_le(), 1.0);
+    assert!(data.read_bool());
+    assert_eq!(data.read_i128_le(), i128::MIN);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn mutable() {
+    let mut bytes = [0u8, 0, 0, 0x2a, 9];
+    let mut data = &mut bytes[..];
+    assert_eq!(data.read_u32_be(), 42);
+    assert_eq!(data.read_array::<1>(), [9]);
+    assert!(data.is_empty());
+}
+
+#[test]
+fn errors() {
+    let mut data = &[1u8, 2, 3][..];
+    assert!(matches!(
+        data.try_read_u32_le(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 3
+        })
+    ));
+    assert_eq!(data.len(), 3);
+    assert!(matches!(
+        (&[2u8][..]).try_read_bool(),
+        Err(AdvanceError::InvalidBool { value: 2 })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "Not enough data")]
+fn panics() {
+    (&[1u8][..]).read_u16_le();
+}
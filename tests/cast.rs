@@ -0,0 +1,99 @@
+//! Zero-copy casts of advanced over bytes to `Pod` types.
+
+use advancer::{AdvanceAs, AdvanceAsMut, AdvanceError};
+
+#[repr(C, align(16))]
+struct Aligned([u8; 32]);
+
+#[test]
+fn cast() {
+    let mut buffer = Aligned([0; 32]);
+    buffer.0[..4].copy_from_slice(&7u32.to_ne_bytes());
+    buffer.0[4..8].copy_from_slice(&(-1i16).to_ne_bytes().repeat(2));
+    let mut data = &buffer.0[..];
+    assert_eq!(*data.advance_as::<u32>(), 7);
+    assert_eq!(data.advance_slice_as::<i16>(2), &[-1, -1]);
+    assert_eq!(data.advance_as::<[u8; 8]>(), &[0; 8]);
+    assert_eq!(data.len(), 16);
+    assert_eq!(data.advance_slice_as::<u64>(0), &[] as &[u64]);
+    assert_eq!(data.len(), 16);
+}
+
+#[test]
+fn cast_mut() {
+    let mut buffer = Aligned([0; 32]);
+    let mut data = &mut buffer.0[..];
+    *data.advance_as_mut::<u64>() = u64::MAX;
+    data.advance_slice_as_mut::<u32>(2).copy_from_slice(&[1, 2]);
+    assert_eq!(*data.advance_as::<u128>(), 0);
+    assert!(data.is_empty());
+    assert_eq!(buffer.0[..8], [0xff; 8]);
+    assert_eq!(buffer.0[8..12], 1u32.to_ne_bytes());
+}
+
+#[test]
+fn misaligned() {
+    let buffer = Aligned([0; 32]);
+    let mut data = &buffer.0[1..];
+    let address = data.as_ptr() as usize;
+    assert!(matches!(
+        data.try_advance_as::<u32>(),
+        Err(AdvanceError::Misaligned { align: 4, address: found }) if found == address
+    ));
+    assert!(matches!(
+        data.try_advance_slice_as::<u16>(2),
+        Err(AdvanceError::Misaligned { align: 2, .. })
+    ));
+    assert_eq!(data.len(), 31);
+    assert_eq!(*data.advance_as::<u8>(), 0);
+    assert_eq!(*data.advance_as::<u16>(), 0);
+}
+
+#[test]
+fn not_enough_data() {
+    let buffer = Aligned([0; 32]);
+    let mut data = &buffer.0[..6];
+    assert!(matches!(
+        data.try_advance_as::<u64>(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 8,
+            remaining: 6
+        })
+    ));
+    assert!(matches!(
+        data.try_advance_slice_as::<u16>(4),
+        Err(AdvanceError::NotEnoughData {
+            needed: 8,
+            remaining: 6
+        })
+    ));
+    assert_eq!(data.len(), 6);
+}
+
+#[test]
+fn size_overflow() {
+    let mut buffer = Aligned([0; 32]);
+    let mut data = &buffer.0[..];
+    assert!(matches!(
+        data.try_advance_slice_as::<u32>(usize::MAX / 2),
+        Err(AdvanceError::SizeOverflow {
+            size: 4,
+            count
+        }) if count == usize::MAX / 2
+    ));
+    assert_eq!(data.len(), 32);
+    let mut data = &mut buffer.0[..];
+    assert!(matches!(
+        data.try_advance_slice_as_mut::<[u64; 2]>(usize::MAX),
+        Err(AdvanceError::SizeOverflow { size: 16, .. })
+    ));
+    assert_eq!(data.len(), 32);
+}
+
+#[test]
+#[should_panic(expected = "Misaligned data")]
+fn misaligned_panics() {
+    let buffer = Aligned([0; 32]);
+    let mut data = &buffer.0[2..];
+    data.advance_as::<u64>();
+}
@@ -0,0 +1,70 @@
+//! Array chunk iterators.
+
+use advancer::{AdvanceBytes, ArrayChunks, ArrayChunksMut, Cursor};
+
+#[test]
+fn forward_and_back() {
+    let data = [1, 2, 3, 4, 5, 6, 7, 8];
+    let mut chunks = ArrayChunks::<_, 3>::new(&data);
+    assert_eq!(chunks.len(), 2);
+    assert_eq!(chunks.remainder(), [7, 8]);
+    assert_eq!(chunks.next_back(), Some(&[4, 5, 6]));
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(chunks.next(), Some(&[1, 2, 3]));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.next_back(), None);
+    assert_eq!(chunks.next(), None);
+    // The remainder is never yielded
+    assert_eq!(chunks.remainder(), [7, 8]);
+
+    let reversed: Vec<_> = ArrayChunks::<_, 2>::new(&data).rev().collect();
+    assert_eq!(reversed, [&[7, 8], &[5, 6], &[3, 4], &[1, 2]]);
+    assert_eq!(ArrayChunks::<_, 2>::new(&data).remainder(), []);
+}
+
+#[test]
+fn short() {
+    let data = [1, 2];
+    let mut chunks = ArrayChunks::<_, 4>::from(&data[..]);
+    assert_eq!(chunks.size_hint(), (0, Some(0)));
+    assert_eq!(chunks.next(), None);
+    assert_eq!(chunks.remainder(), [1, 2]);
+}
+
+#[test]
+fn from_cursor() {
+    let data = [1u8, 2, 3, 4, 5];
+    let mut cursor = Cursor::new(&data[..]);
+    cursor.read_u8();
+    let chunks = ArrayChunks::<_, 2>::from(cursor);
+    assert_eq!(chunks.clone().count(), 2);
+    assert_eq!(chunks.copied().collect::<Vec<_>>(), [[2, 3], [4, 5]]);
+}
+
+#[test]
+fn mutable() {
+    let mut data = [1, 2, 3, 4, 5, 6, 7];
+    let mut chunks = ArrayChunksMut::<_, 2>::new(&mut data);
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(chunks.remainder(), [7]);
+    chunks.remainder_mut()[0] = 70;
+    chunks.next_back().unwrap().swap(0, 1);
+    for chunk in &mut chunks {
+        chunk[0] *= 10;
+    }
+    assert_eq!(chunks.next_back(), None);
+    chunks.into_remainder()[0] += 1;
+    assert_eq!(data, [10, 2, 30, 4, 6, 5, 71]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn zero() {
+    ArrayChunks::<u8, 0>::new(&[1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn zero_mut() {
+    ArrayChunksMut::<u8, 0>::new(&mut [1, 2]);
+}
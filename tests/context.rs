@@ -0,0 +1,91 @@
+//! Field path context on advancing errors.
+
+use advancer::{
+    Advance, AdvanceBytes, AdvanceError, ArrayPath, Context, ContextError, Cursor, FieldPath,
+};
+
+type Error = ContextError<ArrayPath<3>>;
+
+fn header(mut data: &[u8]) -> Result<(u8, u32), Error> {
+    let kind = data.try_read_u8().context("kind")?;
+    let size = data.try_read_u32_le().context("size")?;
+    Ok((kind, size))
+}
+
+fn account(data: &[u8]) -> Result<(u8, u32), Error> {
+    header(data).context("header").context("account")
+}
+
+#[test]
+fn path() {
+    assert_eq!(account(&[1, 2, 0, 0, 0]).unwrap(), (1, 2));
+    let error = account(&[1, 2]).unwrap_err();
+    assert_eq!(error.path.fields(), &["account", "header", "size"]);
+    assert!(matches!(
+        error.error,
+        AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 1
+        }
+    ));
+    assert_eq!(
+        error.to_string(),
+        "account.header.size: not enough data (needed 4, remaining 1)"
+    );
+}
+
+#[test]
+fn offset() {
+    let bytes = [0u8; 215];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.advance(212);
+    let error: Error = cursor
+        .try_read_u64_le()
+        .context("amount")
+        .context("fleet.cargo_hold")
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "fleet.cargo_hold.amount: not enough data (needed 8, remaining 3 at offset 212)"
+    );
+    let error = Error::from(AdvanceError::NotEnoughData {
+        needed: 8,
+        remaining: 3,
+    });
+    assert_eq!(error.to_string(), "not enough data (needed 8, remaining 3)");
+}
+
+#[test]
+fn empty_path() {
+    let error = ContextError::<ArrayPath<2>>::from(AdvanceError::NaN);
+    assert!(error.path.is_empty());
+    assert_eq!(error.to_string(), "NaN is not allowed");
+}
+
+#[test]
+fn truncated() {
+    let error: Result<(), ContextError<ArrayPath<2>>> = Err(AdvanceError::NaN)
+        .context("c")
+        .context("b.x")
+        .context("a");
+    let error = error.unwrap_err();
+    assert!(error.path.is_truncated());
+    assert!(!error.path.is_empty());
+    assert_eq!(error.path.fields(), &["b.x", "c"]);
+    assert_eq!(error.to_string(), "...b.x.c: NaN is not allowed");
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn unbounded() {
+    use advancer::VecPath;
+
+    let mut error: Result<(), ContextError<VecPath>> = Err(AdvanceError::NaN).context("z");
+    for _ in 0..10 {
+        error = error.context("y");
+    }
+    let error = error.unwrap_err();
+    assert_eq!(error.path.fields().count(), 11);
+    assert_eq!(error.path.fields().last(), Some("z"));
+    assert!(error.to_string().starts_with("y.y.y."));
+}
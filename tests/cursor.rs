@@ -0,0 +1,178 @@
+//! Offset-tracking cursors with seeking and checkpoints.
+
+use advancer::{
+    Advance, AdvanceAlign, AdvanceArray, AdvanceBytes, AdvanceError, AdvancePeek, AdvanceWrite,
+    Cursor, CursorMut, SeekFrom,
+};
+
+#[test]
+fn position() {
+    let bytes = [1u8, 2, 3, 4, 5];
+    let mut cursor = Cursor::new(&bytes[..]);
+    let first = cursor.advance(2);
+    let second = cursor.advance_array::<1>();
+    assert_eq!(first, &[1, 2]);
+    assert_eq!(second, &[3]);
+    assert_eq!(cursor.position(), 3);
+    assert_eq!(cursor.consumed(), &[1, 2, 3]);
+    assert_eq!(cursor.remaining(), &[4, 5]);
+    assert_eq!(&*cursor, &[4, 5]);
+    assert_eq!(cursor.get_ref(), &bytes);
+    cursor.rewind();
+    assert_eq!(cursor.read_u8(), 1);
+}
+
+#[test]
+fn seek() {
+    let bytes = [0u8; 10];
+    let mut cursor = Cursor::new(&bytes[..]);
+    assert_eq!(cursor.seek(SeekFrom::Start(4)).unwrap(), 4);
+    assert_eq!(cursor.seek(SeekFrom::Current(3)).unwrap(), 7);
+    assert_eq!(cursor.seek(SeekFrom::Current(-7)).unwrap(), 0);
+    assert_eq!(cursor.seek(SeekFrom::End(-2)).unwrap(), 8);
+    assert_eq!(cursor.seek(SeekFrom::End(0)).unwrap(), 10);
+    assert_eq!(cursor.seek(SeekFrom::Start(10)).unwrap(), 10);
+    assert!(cursor.is_empty());
+}
+
+#[test]
+fn seek_out_of_bounds() {
+    let bytes = [0u8; 10];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.seek(SeekFrom::Start(5)).unwrap();
+    for from in [
+        SeekFrom::Start(11),
+        SeekFrom::Start(usize::MAX),
+        SeekFrom::End(1),
+        SeekFrom::End(-11),
+        SeekFrom::End(isize::MIN),
+        SeekFrom::Current(6),
+        SeekFrom::Current(-6),
+        SeekFrom::Current(isize::MAX),
+    ] {
+        match cursor.seek(from) {
+            Err(AdvanceError::SeekOutOfBounds {
+                from: found,
+                length: 10,
+            }) => assert_eq!(found, from),
+            result => panic!("{:?} gave {:?}", from, result),
+        }
+        assert_eq!(cursor.position(), 5);
+    }
+}
+
+#[test]
+fn checkpoint() {
+    let bytes = [1u8, 2, 3, 4];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.advance(1);
+    let checkpoint = cursor.checkpoint();
+    assert_eq!(checkpoint.position(), 1);
+    assert_eq!(cursor.read_u16_le(), 0x0302);
+    cursor.restore(checkpoint);
+    assert_eq!(cursor.read_array::<3>(), [2, 3, 4]);
+}
+
+#[test]
+#[should_panic]
+fn restore_out_of_bounds() {
+    let long = [0u8; 4];
+    let mut cursor = Cursor::new(&long[..]);
+    cursor.advance(4);
+    let checkpoint = cursor.checkpoint();
+    let mut short = Cursor::new(&long[..2]);
+    short.restore(checkpoint);
+}
+
+#[test]
+fn align() {
+    let bytes = [0u8, 1, 2, 3, 4, 5, 6, 7, 8];
+    let mut cursor = Cursor::new(&bytes[1..]);
+    cursor.advance(1);
+    assert_eq!(cursor.padding_to(4).unwrap(), 3);
+    cursor.align_forward(4);
+    assert_eq!(cursor.position(), 4);
+    assert_eq!(cursor.advance(1), &[5]);
+}
+
+#[test]
+fn cursor_mut() {
+    let mut bytes = [0u8; 8];
+    let mut cursor = CursorMut::new(&mut bytes[..]);
+    cursor.write_u16_be(0x0102).unwrap();
+    cursor.advance(1)[0] = 9;
+    let checkpoint = cursor.checkpoint();
+    cursor.write_u8(3).unwrap();
+    cursor.restore(checkpoint);
+    cursor.write_u8(4).unwrap();
+    cursor.seek(SeekFrom::End(-1)).unwrap();
+    cursor.remaining_mut()[0] = 0xff;
+    assert_eq!(cursor.consumed(), &[1, 2, 9, 4, 0, 0, 0]);
+    cursor.seek(SeekFrom::Start(2)).unwrap();
+    let (consumed, remaining) = cursor.into_parts();
+    assert_eq!(consumed, &[1, 2]);
+    assert_eq!(remaining, &[9, 4, 0, 0, 0, 0xff]);
+    assert!(matches!(
+        CursorMut::new(&mut bytes[..]).seek(SeekFrom::Current(9)),
+        Err(AdvanceError::SeekOutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn offsets() {
+    let bytes = [0u8, 1, 2, 3, 4, 5];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.advance(4);
+    let at = |needed| AdvanceError::NotEnoughDataAt {
+        needed,
+        remaining: 2,
+        offset: 4,
+        length: 6,
+    };
+    for (result, needed) in [
+        (cursor.try_advance(3).map(drop), 3),
+        (cursor.try_advance_array::<5>().map(drop), 5),
+        (cursor.try_read_u32_le().map(drop), 4),
+        (Advance::try_advance(&mut &mut cursor, 3).map(drop), 3),
+        (cursor.try_skip(3), 3),
+    ] {
+        let error = result.unwrap_err();
+        assert_eq!(error.offset(), Some(4));
+        assert_eq!(error.to_string(), at(needed).to_string());
+    }
+    assert_eq!(cursor.position(), 4);
+}
+
+#[test]
+fn offsets_mut() {
+    let mut bytes = [0u8; 5];
+    let mut cursor = CursorMut::new(&mut bytes[..]);
+    cursor.seek(SeekFrom::Start(3)).unwrap();
+    assert!(matches!(
+        cursor.write_u32_le(0),
+        Err(AdvanceError::NotEnoughDataAt {
+            needed: 4,
+            remaining: 2,
+            offset: 3,
+            length: 5
+        })
+    ));
+}
+
+#[test]
+#[should_panic(expected = "offset: `2`, length: `3`")]
+fn advance_panics_with_offset() {
+    let bytes = [0u8; 3];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.advance(2);
+    cursor.advance(2);
+}
+
+#[test]
+#[should_panic(expected = "offset: `1`, length: `3`")]
+fn advance_array_panics_with_offset() {
+    let mut bytes = [0u8; 3];
+    let mut cursor = CursorMut::new(&mut bytes[..]);
+    cursor.advance(1);
+    cursor.advance_array::<4>();
+}
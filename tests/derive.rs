@@ -0,0 +1,180 @@
+//! `#[derive(Decode, Encode)]` round trips.
+
+#![cfg(feature = "derive")]
+
+use advancer::{AdvanceError, Decode, Encode, Endian};
+use core::fmt::Debug;
+
+/// Encodes `value` with `endian` over a dirty buffer, so unwritten padding shows up, then
+/// checks the output is `bytes` and that decoding them gives `value` back using every byte.
+fn round_trip<T: Decode + Encode + PartialEq + Debug>(value: T, endian: Endian, bytes: &[u8]) {
+    let mut buffer = [0xaa; 64];
+    let mut writer = &mut buffer[..];
+    value.encode_endian(&mut writer, endian).unwrap();
+    let written = 64 - writer.len();
+    assert_eq!(&buffer[..written], bytes);
+    let mut reader = bytes;
+    assert_eq!(T::decode_endian(&mut reader, endian).unwrap(), value);
+    assert!(reader.is_empty());
+}
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+struct Header {
+    endian: u8,
+    value: u32,
+}
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+struct Io {
+    reader: u8,
+    writer: u16,
+    tag: u8,
+    __endian: u8,
+}
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+struct Pair(u16, #[advancer(endian = "big")] u16);
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+#[advancer(endian = "big")]
+struct Padded {
+    #[advancer(pad_before = 1)]
+    first: u16,
+    #[advancer(pad_after = 2, endian = "little")]
+    second: u16,
+    #[advancer(skip)]
+    cached: u64,
+    last: u8,
+}
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+struct Generic<R, T> {
+    reader: R,
+    values: [T; 2],
+}
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+#[repr(u8)]
+enum Message {
+    Ping,
+    Data { len: u16, endian: bool },
+    Pair(u8, u8),
+    Far = 10,
+    Next,
+}
+
+#[derive(Decode, Encode, PartialEq, Debug)]
+#[advancer(tag = "u16", endian = "big")]
+#[repr(u16)]
+enum Wide {
+    One(u16) = 0x0102,
+    Two,
+}
+
+#[test]
+fn shadowing_names() {
+    round_trip(
+        Header {
+            endian: 1,
+            value: 2,
+        },
+        Endian::Big,
+        &[1, 0, 0, 0, 2],
+    );
+    round_trip(
+        Io {
+            reader: 1,
+            writer: 2,
+            tag: 3,
+            __endian: 4,
+        },
+        Endian::Little,
+        &[1, 2, 0, 3, 4],
+    );
+}
+
+#[test]
+fn tuple_struct() {
+    round_trip(Pair(1, 2), Endian::Little, &[1, 0, 0, 2]);
+    round_trip(Pair(1, 2), Endian::Big, &[0, 1, 0, 2]);
+}
+
+#[test]
+fn attributes() {
+    round_trip(
+        Padded {
+            first: 1,
+            second: 2,
+            cached: 0,
+            last: 3,
+        },
+        Endian::Little,
+        &[0, 0, 1, 2, 0, 0, 0, 3],
+    );
+    let mut reader = &[9u8, 0, 1, 2, 0, 9, 9, 3][..];
+    let value = Padded::decode(&mut reader).unwrap();
+    assert_eq!(value.cached, 0);
+    assert_eq!((value.first, value.second, value.last), (1, 2, 3));
+}
+
+#[test]
+fn generics() {
+    round_trip(
+        Generic {
+            reader: 7u8,
+            values: [1i16, -1],
+        },
+        Endian::Big,
+        &[7, 0, 1, 0xff, 0xff],
+    );
+}
+
+#[test]
+fn enums() {
+    round_trip(Message::Ping, Endian::Little, &[0]);
+    round_trip(
+        Message::Data {
+            len: 3,
+            endian: true,
+        },
+        Endian::Little,
+        &[1, 3, 0, 1],
+    );
+    round_trip(Message::Pair(4, 5), Endian::Little, &[2, 4, 5]);
+    round_trip(Message::Far, Endian::Little, &[10]);
+    round_trip(Message::Next, Endian::Little, &[11]);
+    round_trip(Wide::One(3), Endian::Little, &[1, 2, 0, 3]);
+    round_trip(Wide::Two, Endian::Little, &[1, 3]);
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        Message::decode(&mut &[3u8][..]),
+        Err(AdvanceError::InvalidTag { tag: 3 })
+    ));
+    assert!(matches!(
+        Wide::decode(&mut &[0u8, 1][..]),
+        Err(AdvanceError::InvalidTag { tag: 1 })
+    ));
+    assert!(matches!(
+        Message::decode(&mut &[1u8, 0, 0, 2][..]),
+        Err(AdvanceError::InvalidBool { value: 2 })
+    ));
+    assert!(matches!(
+        Header::decode(&mut &[1u8, 0][..]),
+        Err(AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 1
+        })
+    ));
+    let mut buffer = [0u8; 2];
+    assert!(Padded {
+        first: 0,
+        second: 0,
+        cached: 0,
+        last: 0
+    }
+    .encode(&mut &mut buffer[..])
+    .is_err());
+}
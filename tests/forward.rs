@@ -0,0 +1,89 @@
+//! Forwarding impls for references to and borrows of advancers.
+
+use advancer::forward::{borrow_slice, borrow_slice_mut, try_borrow_slice, try_borrow_slice_mut};
+use advancer::{Advance, AdvanceArray, AdvanceBytes, AdvanceError, AdvancePeek, Cursor};
+use core::cell::{Ref, RefCell, RefMut};
+use std::rc::Rc;
+
+/// Decodes a sub-structure through a shared advancer.
+fn read_pair<A: AdvanceBytes>(advancer: &mut A) -> (u8, u16) {
+    (advancer.read_u8(), advancer.read_u16_le())
+}
+
+#[test]
+fn mut_ref() {
+    let data = [1u8, 2, 0, 3, 4, 0];
+    let mut slice = &data[..];
+    assert_eq!(read_pair(&mut &mut slice), (1, 2));
+    assert_eq!(read_pair(&mut slice), (3, 4));
+    assert!(slice.is_empty());
+
+    let mut cursor = Cursor::new(&data[..]);
+    let mut forwarded = &mut cursor;
+    assert_eq!((&mut forwarded).advance_array::<2>(), &[1, 2]);
+    // Errors are still located by the cursor
+    assert!(matches!(
+        Advance::try_advance(&mut forwarded, 5),
+        Err(AdvanceError::NotEnoughDataAt { offset: 2, .. })
+    ));
+    assert_eq!(cursor.position(), 2);
+}
+
+#[test]
+fn ref_mut() {
+    let cell = RefCell::new([1u8, 2, 3, 4, 5]);
+    let mut borrow = RefMut::map(cell.borrow_mut(), |data| &mut data[..]);
+    let mut first = borrow.advance(2);
+    let mut array = borrow.advance_array::<2>();
+    assert!(matches!(
+        borrow.try_advance(2),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+    first[0] = 10;
+    array[1] = 40;
+    assert_eq!(borrow.peek(1), [5]);
+    drop(borrow);
+    // Outputs keep the cell borrowed after the advancer is dropped
+    assert!(cell.try_borrow().is_err());
+    drop((first, array));
+    assert_eq!(*cell.borrow(), [10, 2, 3, 40, 5]);
+}
+
+#[test]
+fn account_data() {
+    let mut lamports = [1u8, 2, 3, 4, 5, 6];
+    // Solana's `AccountInfo::data` is a `Rc<RefCell<&mut [u8]>>`
+    let data = Rc::new(RefCell::new(&mut lamports[..]));
+    {
+        let mut borrow = borrow_slice_mut(&data);
+        borrow.advance(2).fill(0);
+        assert_eq!(borrow.read_u16_le(), 0x0403);
+        assert_eq!(borrow.len(), 2);
+        assert!(try_borrow_slice(&data).is_err());
+    }
+    // Advancing never changes the slice stored in the cell
+    assert_eq!(data.borrow().len(), 6);
+    {
+        let mut borrow = borrow_slice(&data);
+        assert_eq!(&*borrow.advance(4), [0, 0, 3, 4]);
+        assert_eq!(borrow.read_u16_le(), 0x0605);
+        assert!(try_borrow_slice_mut(&data).is_err());
+        assert_eq!(&*try_borrow_slice(&data).unwrap(), [0, 0, 3, 4, 5, 6]);
+    }
+    assert_eq!(data.borrow().len(), 6);
+    assert!(try_borrow_slice_mut(&data).is_ok());
+}
+
+#[test]
+fn shared_ref() {
+    let cell = RefCell::new(vec![1u8, 2, 3]);
+    let mut borrow = Ref::map(cell.borrow(), |data| &data[..]);
+    let first = borrow.advance_array::<1>();
+    let second = cell.borrow();
+    assert_eq!((*first, &second[..]), ([1], &[1, 2, 3][..]));
+    assert_eq!(&*borrow, [2, 3]);
+    assert!(cell.try_borrow_mut().is_err());
+}
@@ -0,0 +1,115 @@
+//! `Length` for collections and `#[derive(Length)]` newtypes.
+
+use advancer::Length;
+
+/// Generic code bounded on `Length`.
+fn length<L: Length + ?Sized>(value: &L) -> (usize, bool) {
+    (value.len(), value.is_empty())
+}
+
+#[test]
+fn core() {
+    assert_eq!(length("héllo"), (6, false));
+    assert_eq!(length(&""), (0, true));
+    assert_eq!(length(&[1u8, 2][..]), (2, false));
+    assert_eq!(length(&[0u16; 3]), (3, false));
+    assert_eq!(length(&&mut [0u8; 4][..]), (4, false));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn alloc() {
+    use advancer::Cursor;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+    use std::sync::Arc;
+
+    assert_eq!(length(&vec![1u8, 2, 3]), (3, false));
+    assert_eq!(length(&Vec::<u32>::new()), (0, true));
+    let mut deque: VecDeque<u8> = (0..5).collect();
+    deque.rotate_left(3);
+    assert_eq!(length(&deque), (5, false));
+    assert_eq!(length(&String::from("héllo")), (6, false));
+    assert_eq!(length(&Box::<[u8]>::from([1, 2])), (2, false));
+    assert_eq!(length(&Box::new(String::new())), (0, true));
+    assert_eq!(length(&Rc::<[u8]>::from([1, 2, 3])), (3, false));
+    assert_eq!(length(&Arc::<str>::from("ab")), (2, false));
+    assert_eq!(length(&Box::new(Cursor::new(&[1u8, 2][..]))), (2, false));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn alloc_forwards_locate() {
+    use advancer::{AdvanceBytes, AdvanceError, Cursor};
+
+    let mut cursor = Cursor::new(&[1u8, 2, 3][..]);
+    cursor.read_u8();
+    let boxed = Box::new(cursor);
+    assert!(matches!(
+        boxed.locate(AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 2
+        }),
+        AdvanceError::NotEnoughDataAt {
+            needed: 4,
+            remaining: 2,
+            offset: 1,
+            length: 3
+        }
+    ));
+}
+
+#[cfg(feature = "derive")]
+mod derive {
+    use super::length;
+    use advancer::{AdvanceBytes, AdvanceError, Cursor, Length};
+
+    #[derive(Length)]
+    struct Buffer<'a>(Cursor<'a, u8>);
+
+    #[derive(Length)]
+    struct Named<L: ?Sized> {
+        inner: L,
+    }
+
+    #[test]
+    fn newtypes() {
+        assert_eq!(length(&Buffer(Cursor::new(&[1, 2, 3]))), (3, false));
+        assert_eq!(length(&Named { inner: [0u8; 0] }), (0, true));
+        let named: &Named<[u8]> = &Named { inner: [1, 2] };
+        assert_eq!(length(named), (2, false));
+        assert_eq!(length(&Named { inner: "abc" }), (3, false));
+    }
+
+    #[test]
+    fn forwards_locate() {
+        let mut cursor = Cursor::new(&[1u8, 2, 3][..]);
+        cursor.read_u16_le();
+        let buffer = Named {
+            inner: Buffer(cursor),
+        };
+        assert!(matches!(
+            buffer.locate(AdvanceError::NotEnoughData {
+                needed: 2,
+                remaining: 1
+            }),
+            AdvanceError::NotEnoughDataAt {
+                needed: 2,
+                remaining: 1,
+                offset: 2,
+                length: 3
+            }
+        ));
+        // Types without a position leave errors as they are
+        assert!(matches!(
+            Named { inner: [0u8; 1] }.locate(AdvanceError::NotEnoughData {
+                needed: 2,
+                remaining: 1
+            }),
+            AdvanceError::NotEnoughData {
+                needed: 2,
+                remaining: 1
+            }
+        ));
+    }
+}
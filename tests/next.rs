@@ -0,0 +1,91 @@
+//! Advancing traits whose outputs borrow the underlying data.
+
+use advancer::next::{Advance, AdvanceArray};
+use advancer::{AdvanceError, Cursor};
+
+/// Generic code needs no higher ranked bounds.
+fn split_header<A: Advance<Element = u8>>(
+    advancer: &mut A,
+) -> Result<(A::AdvanceOut, A::AdvanceOut), AdvanceError> {
+    let header = advancer.try_advance(2)?;
+    let body = advancer.try_advance(header[1] as usize)?;
+    Ok((header, body))
+}
+
+#[test]
+fn outputs_outlive_advances() {
+    let data = [1u8, 2, 3, 4, 5, 6];
+    let mut slice = &data[..];
+    let first = slice.advance(1);
+    let array = slice.advance_array::<2>();
+    let rest = slice.try_advance(3).unwrap();
+    assert_eq!((first, array, rest), (&[1][..], &[2, 3], &[4, 5, 6][..]));
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn mutable_outputs_outlive_advances() {
+    let mut data = [1u8, 2, 3, 4];
+    let mut slice = &mut data[..];
+    let first = slice.advance(1);
+    let array = slice.advance_array::<2>();
+    first[0] = 10;
+    array.swap(0, 1);
+    assert!(matches!(
+        slice.try_advance_array::<2>(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+    assert_eq!(slice.len(), 1);
+    assert_eq!(data, [10, 3, 2, 4]);
+}
+
+#[test]
+fn generic() {
+    let data = [0u8, 2, 7, 8, 9];
+    let mut slice = &data[..];
+    let (header, body) = split_header(&mut slice).unwrap();
+    assert_eq!((header, body), (&[0, 2][..], &[7, 8][..]));
+
+    let mut cursor = Cursor::new(&data[..]);
+    let (header, body) = split_header(&mut cursor).unwrap();
+    assert_eq!((header, body), (&[0, 2][..], &[7, 8][..]));
+    assert_eq!(cursor.position(), 4);
+    assert!(matches!(
+        split_header(&mut cursor),
+        Err(AdvanceError::NotEnoughDataAt {
+            needed: 2,
+            remaining: 1,
+            offset: 4,
+            length: 5
+        })
+    ));
+    assert_eq!(cursor.position(), 4);
+}
+
+#[test]
+fn cursor() {
+    let data = [1u8, 2, 3];
+    let mut cursor = Cursor::new(&data[..]);
+    let array = AdvanceArray::advance_array::<2>(&mut cursor);
+    let last = Advance::advance(&mut cursor, 1);
+    assert_eq!((array, last), (&[1, 2], &[3][..]));
+}
+
+#[test]
+#[should_panic(expected = "needed: `3`")]
+fn panics() {
+    let mut slice = &[0u8; 2][..];
+    Advance::advance(&mut slice, 3);
+}
+
+#[test]
+#[should_panic(expected = "offset: `3`, length: `3`")]
+fn cursor_panics_with_offset() {
+    let bytes = [0u8; 3];
+    let mut cursor = Cursor::new(&bytes[..]);
+    Advance::advance(&mut cursor, 3);
+    AdvanceArray::advance_array::<1>(&mut cursor);
+}
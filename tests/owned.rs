@@ -0,0 +1,148 @@
+//! Advancing owned buffers into owned pieces.
+
+#![cfg(feature = "alloc")]
+
+use advancer::{
+    Advance, AdvanceArray, AdvanceBytes, AdvanceError, AdvanceVec, Endian, OwnedArray, VecAdvancer,
+};
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+#[test]
+fn vec() {
+    let mut advancer = VecAdvancer::from(vec![1u8, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(advancer.advance(2), [1, 2]);
+    assert_eq!(advancer.advance_array::<2>(), OwnedArray([3, 4]));
+    assert_eq!(advancer.read_u8(), 5);
+    assert_eq!(advancer.position(), 5);
+    assert_eq!(advancer.remaining(), [6, 7]);
+    assert!(matches!(
+        advancer.try_advance(3),
+        Err(AdvanceError::NotEnoughData {
+            needed: 3,
+            remaining: 2
+        })
+    ));
+    advancer[0] = 60;
+    assert_eq!(
+        format!("{advancer:?}"),
+        "VecAdvancer { remaining: [60, 7], position: 5 }"
+    );
+    let clone = advancer.clone();
+    assert_eq!((clone.position(), clone.remaining()), (5, &[60, 7][..]));
+    assert_eq!(clone.into_vec(), [60, 7]);
+    let vec = advancer.into_vec();
+    // The remaining elements are moved to the front of the same allocation
+    assert_eq!(vec, [60, 7]);
+    assert!(vec.capacity() >= 7);
+}
+
+#[test]
+fn vec_drops() {
+    let value = Rc::new(());
+    let mut advancer = VecAdvancer::new(vec![value.clone(); 6]);
+    let first = advancer.advance(2);
+    let array = advancer.advance_array::<1>();
+    assert_eq!(Rc::strong_count(&value), 7);
+    drop((first, array));
+    assert_eq!(Rc::strong_count(&value), 4);
+    // Clones hold only the remaining elements, at the same position
+    let clone = advancer.clone();
+    assert_eq!((clone.position(), Rc::strong_count(&value)), (3, 7));
+    drop(clone);
+    assert_eq!(Rc::strong_count(&value), 4);
+    // Only the remaining elements are dropped with the advancer
+    drop(advancer);
+    assert_eq!(Rc::strong_count(&value), 1);
+
+    let mut advancer = VecAdvancer::new(vec![value.clone(); 3]);
+    advancer.advance(1);
+    let vec = advancer.into_vec();
+    assert_eq!((vec.len(), Rc::strong_count(&value)), (2, 3));
+    drop(vec);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn into_iter() {
+    let mut iter = vec![1u8, 2, 3, 4, 5].into_iter();
+    assert_eq!(iter.advance(1), [1]);
+    assert_eq!(*iter.advance_array::<2>(), [2, 3]);
+    assert_eq!(iter.read_u16_le(), 0x0504);
+    assert!(iter.try_read_u8().is_err());
+
+    let value = Rc::new(());
+    let mut iter = vec![value.clone(); 3].into_iter();
+    let OwnedArray([a, b]) = iter.advance_array::<2>();
+    drop((a, iter));
+    assert_eq!(Rc::strong_count(&value), 2);
+    drop(b);
+    assert_eq!(Rc::strong_count(&value), 1);
+}
+
+#[test]
+fn vec_deque() {
+    let mut deque: VecDeque<u8> = (1..=6).collect();
+    deque.rotate_left(2);
+    assert_eq!(deque.advance(2), [3, 4]);
+    assert_eq!(deque.advance_array::<2>().into_inner(), [5, 6]);
+    // Reads across the wrap around of the ring buffer
+    deque.push_back(7);
+    assert_eq!(deque.read_u16_be(), 0x0102);
+    assert_eq!(deque.read_u8(), 7);
+    assert!(matches!(
+        deque.try_read_u8(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 1,
+            remaining: 0
+        })
+    ));
+}
+
+#[test]
+fn advance_vec() {
+    let mut data = &[1u8, 0, 2, 0, 0, 3][..];
+    assert_eq!(data.advance_vec::<u16>(2), [1, 2]);
+    assert_eq!(
+        data.try_advance_vec_endian::<u16>(1, Endian::Big).unwrap(),
+        [3]
+    );
+    assert!(data.is_empty());
+    assert_eq!(data.advance_vec::<u8>(0), []);
+}
+
+#[test]
+fn advance_vec_zero_sized() {
+    // Zero sized values need no data, so any count would otherwise decode
+    let mut data = &[1u8][..];
+    assert!(matches!(
+        data.try_advance_vec::<()>(usize::MAX),
+        Err(AdvanceError::ZeroSizedElements)
+    ));
+    assert!(matches!(
+        data.try_advance_vec::<[u8; 0]>(1),
+        Err(AdvanceError::ZeroSizedElements)
+    ));
+    assert_eq!(data, [1]);
+}
+
+#[test]
+fn advance_vec_bogus_count() {
+    // A huge count errors on the missing data rather than trying to reserve it up front
+    let mut data = &[1u8, 2, 3][..];
+    assert!(matches!(
+        data.try_advance_vec::<u64>(usize::MAX),
+        Err(AdvanceError::NotEnoughData {
+            needed: 8,
+            remaining: 3
+        })
+    ));
+    let mut data = VecAdvancer::new(vec![7u8; 4]);
+    assert!(data.try_advance_vec::<u8>(1 << 40).is_err());
+}
+
+#[test]
+#[should_panic(expected = "needed: `4`")]
+fn panics() {
+    VecAdvancer::new(vec![0u8; 3]).advance_array::<4>();
+}
@@ -0,0 +1,101 @@
+//! Peeking at and skipping over the front of an advancer.
+
+use advancer::{AdvanceError, AdvancePeek, Cursor};
+
+#[test]
+fn peek() {
+    let data = [1u8, 2, 3, 4];
+    let mut slice = &data[..];
+    assert_eq!(slice.peek(2), [1, 2]);
+    assert_eq!(slice.peek_array::<4>(), &data);
+    assert_eq!(slice.peek(0), []);
+    assert!(matches!(
+        slice.try_peek(5),
+        Err(AdvanceError::NotEnoughData {
+            needed: 5,
+            remaining: 4
+        })
+    ));
+    assert!(slice.try_peek_array::<5>().is_err());
+    assert_eq!(slice, data);
+
+    slice.skip(1);
+    assert_eq!(slice.peek_array::<3>(), &[2, 3, 4]);
+}
+
+#[test]
+fn peek_at() {
+    let data = [1u8, 2, 3, 4];
+    let slice = &data[..];
+    assert_eq!(slice.peek_at(1, 2), [2, 3]);
+    assert_eq!(slice.peek_at(4, 0), []);
+    assert!(matches!(
+        slice.try_peek_at(3, 2),
+        Err(AdvanceError::NotEnoughData {
+            needed: 5,
+            remaining: 4
+        })
+    ));
+    assert!(matches!(
+        slice.try_peek_at(5, 0),
+        Err(AdvanceError::NotEnoughData {
+            needed: 5,
+            remaining: 4
+        })
+    ));
+    // An end past `usize::MAX` saturates instead of wrapping around
+    for (offset, amount) in [(usize::MAX, 1), (2, usize::MAX), (usize::MAX, usize::MAX)] {
+        assert!(matches!(
+            slice.try_peek_at(offset, amount),
+            Err(AdvanceError::NotEnoughData {
+                needed: usize::MAX,
+                remaining: 4
+            })
+        ));
+    }
+}
+
+#[test]
+fn skip() {
+    let mut data = [1u8, 2, 3, 4];
+    let mut slice = &mut data[..];
+    slice.skip(2);
+    assert!(matches!(
+        slice.try_skip(3),
+        Err(AdvanceError::NotEnoughData {
+            needed: 3,
+            remaining: 2
+        })
+    ));
+    assert_eq!(slice.peek(2), [3, 4]);
+    slice.peek(0);
+    slice.skip(2);
+    assert!(slice.is_empty());
+}
+
+#[test]
+fn cursor_offsets() {
+    let mut cursor = Cursor::new(&[1u8, 2, 3][..]);
+    cursor.skip(1);
+    assert_eq!(cursor.peek(2), [2, 3]);
+    assert!(matches!(
+        cursor.try_peek_at(1, 2),
+        Err(AdvanceError::NotEnoughDataAt {
+            needed: 3,
+            remaining: 2,
+            offset: 1,
+            length: 3
+        })
+    ));
+    assert!(matches!(
+        cursor.try_skip(3),
+        Err(AdvanceError::NotEnoughDataAt { offset: 1, .. })
+    ));
+    assert_eq!(cursor.position(), 1);
+}
+
+#[test]
+#[should_panic(expected = "needed: `2`")]
+fn panics() {
+    (&[0u8][..]).peek(2);
+}
@@ -0,0 +1,132 @@
+//! Runs of data preceded by their length.
+
+use advancer::prefix::{CompactU16, LengthPrefix, U16Be, U16Le, U32Le, U64Be, Varint, U8};
+use advancer::{AdvanceError, AdvanceLenPrefixed, AdvanceLenPrefixedWrite, AdvancePeek, Cursor};
+
+/// Writes `bytes` prefixed with a `P` length, returning the written bytes.
+fn prefixed<P: LengthPrefix>(bytes: &[u8]) -> Vec<u8> {
+    let mut buffer = [0u8; 512];
+    let mut writer = &mut buffer[..];
+    writer.write_len_prefixed::<P>(bytes).unwrap();
+    let written = 512 - writer.len();
+    buffer[..written].to_vec()
+}
+
+#[test]
+fn encodings() {
+    assert_eq!(prefixed::<U8>(b"ab"), [2, b'a', b'b']);
+    assert_eq!(prefixed::<U16Le>(b"ab"), [2, 0, b'a', b'b']);
+    assert_eq!(prefixed::<U16Be>(b"ab"), [0, 2, b'a', b'b']);
+    assert_eq!(prefixed::<U32Le>(b"a"), [1, 0, 0, 0, b'a']);
+    assert_eq!(prefixed::<U64Be>(b""), [0; 8]);
+    assert_eq!(prefixed::<Varint>(&[7; 300])[..2], [0xac, 0x02]);
+    assert_eq!(prefixed::<CompactU16>(&[7; 128])[..2], [0x80, 0x01]);
+}
+
+macro_rules! round_trip {
+    ($($name:ident: $prefix:ty;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                for len in [0, 1, 127, 128, 255] {
+                    let data: Vec<u8> = (0..len as u8).collect();
+                    let bytes = prefixed::<$prefix>(&data);
+                    let mut reader = &bytes[..];
+                    assert_eq!(reader.advance_len_prefixed::<$prefix>(), &data[..]);
+                    assert!(reader.is_empty());
+                }
+            }
+        )*
+    };
+}
+
+round_trip! {
+    round_trip_u8: U8;
+    round_trip_u16_le: U16Le;
+    round_trip_u16_be: U16Be;
+    round_trip_u32_le: U32Le;
+    round_trip_u64_be: U64Be;
+    round_trip_varint: Varint;
+    round_trip_compact_u16: CompactU16;
+}
+
+#[test]
+fn length_overflow() {
+    let mut buffer = [0u8; 512];
+    let mut writer = &mut buffer[..];
+    assert!(matches!(
+        writer.write_len_prefixed::<U8>(&[0; 256]),
+        Err(AdvanceError::LengthOverflow { length: 256 })
+    ));
+    // Nothing is written when the length does not fit
+    assert_eq!(writer.len(), 512);
+    assert!(writer.write_len_prefixed::<U8>(&[0; 255]).is_ok());
+}
+
+#[test]
+fn compact_u16_limits() {
+    let bytes = [0xff, 0xff, 0x03];
+    assert_eq!(U16Le::read_length(&mut &[0xff, 0xff][..]).unwrap(), 0xffff);
+    assert_eq!(CompactU16::read_length(&mut &bytes[..]).unwrap(), 0xffff);
+    assert!(matches!(
+        CompactU16::read_length(&mut &[0xff, 0xff, 0x04][..]),
+        Err(AdvanceError::VarintOverflow { bits: 16 })
+    ));
+    assert!(matches!(
+        CompactU16::read_length(&mut &[0x80, 0x00][..]),
+        Err(AdvanceError::VarintOverlong)
+    ));
+}
+
+#[test]
+fn not_enough_data() {
+    let mut reader = &[3, b'a', b'b'][..];
+    assert!(matches!(
+        reader.try_advance_len_prefixed::<U8>(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 3
+        })
+    ));
+    // Nothing is advanced over, not even the prefix
+    assert_eq!(reader, [3, b'a', b'b']);
+    let mut reader = &mut [0x80, 0x80][..];
+    assert!(reader.try_advance_len_prefixed::<Varint>().is_err());
+    assert_eq!(reader, [0x80, 0x80]);
+
+    let bytes = [0xff, 2, 0, b'a'];
+    let mut cursor = Cursor::new(&bytes[..]);
+    cursor.skip(1);
+    assert!(matches!(
+        cursor.try_advance_len_prefixed::<U16Le>(),
+        Err(AdvanceError::NotEnoughDataAt {
+            needed: 4,
+            remaining: 3,
+            offset: 1,
+            length: 4
+        })
+    ));
+    assert_eq!(cursor.position(), 1);
+}
+
+#[test]
+fn max() {
+    let bytes = prefixed::<U16Le>(b"abcd");
+    assert_eq!(
+        (&mut &bytes[..]).advance_len_prefixed_max::<U16Le>(4),
+        b"abcd"
+    );
+    let mut reader = &bytes[..];
+    assert!(matches!(
+        reader.try_advance_len_prefixed_max::<U16Le>(3),
+        Err(AdvanceError::LengthLimitExceeded { length: 4, max: 3 })
+    ));
+    assert_eq!(reader, bytes);
+}
+
+#[test]
+#[should_panic]
+fn max_panics() {
+    let bytes = prefixed::<Varint>(b"abcd");
+    (&mut &bytes[..]).advance_len_prefixed_max::<Varint>(0);
+}
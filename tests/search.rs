@@ -0,0 +1,149 @@
+//! Searching for predicates and delimiters.
+
+use advancer::{AdvanceError, AdvanceSearch, Cursor, SearchElement};
+
+/// Element by element search to compare the word at a time search against.
+fn naive(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[test]
+fn find_byte_every_position() {
+    let buffer = [0x55u8; 48];
+    for needle in [0x00, 0x01, 0x7f, 0x80, 0xfe, 0xff] {
+        // Unaligned starts, lengths around word boundaries, and a match at every index including the tail
+        for start in 0..8 {
+            for len in 0..40 {
+                assert_eq!(u8::find(&buffer[start..start + len], &[needle]), None);
+                for index in 0..len {
+                    let mut haystack = buffer;
+                    // A later match in the tail must not be found first
+                    haystack[start + len - 1] = needle;
+                    haystack[start + index] = needle;
+                    let haystack = &haystack[start..start + len];
+                    assert_eq!(
+                        u8::find(haystack, &[needle]),
+                        Some(index),
+                        "needle {needle:#x}, start {start}, len {len}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn find_byte_near_misses() {
+    // Bytes that differ from the needle only in the high bit or by one, which word at a time checks can confuse
+    for needle in [0x00u8, 0x01, 0x80, 0xff] {
+        let haystack: Vec<u8> = (0..64u8)
+            .map(|index| match index % 3 {
+                0 => needle ^ 0x80,
+                1 => needle.wrapping_add(1),
+                _ => needle.wrapping_sub(1),
+            })
+            .collect();
+        assert_eq!(u8::find(&haystack, &[needle]), None);
+        for index in 0..haystack.len() {
+            let mut haystack = haystack.clone();
+            haystack[index] = needle;
+            assert_eq!(u8::find(&haystack, &[needle]), Some(index));
+        }
+    }
+}
+
+#[test]
+fn find_sequences() {
+    let haystack = b"aaab, aab, ab, abc\r\n\r\nbody";
+    for needle in [
+        &b""[..],
+        b"ab",
+        b"aab",
+        b"abc",
+        b"\r\n\r\n",
+        b"body",
+        b"bodyx",
+        b"zz",
+    ] {
+        assert_eq!(u8::find(haystack, needle), naive(haystack, needle));
+    }
+    assert_eq!(u16::find(&[1, 2, 3, 2, 3], &[2, 3]), Some(1));
+    assert_eq!(u16::find(&[1, 2], &[2, 3]), None);
+    assert_eq!(char::find(&['a', 'b'], &[]), Some(0));
+}
+
+#[test]
+fn while_and_until() {
+    let data = b"  abc def";
+    let mut slice = &data[..];
+    assert_eq!(slice.advance_while(|byte| *byte == b' '), b"  ");
+    assert_eq!(slice.advance_until(|byte| *byte == b' '), b"abc");
+    assert_eq!(slice.advance_while(|byte| *byte == b'x'), b"");
+    assert_eq!(slice.advance_until(|byte| *byte == b'x'), b" def");
+    assert!(slice.is_empty());
+    assert_eq!(slice.advance_while(|_| true), b"");
+}
+
+#[test]
+fn delimiters() {
+    let mut data = *b"key: value\r\nrest";
+    let mut slice = &data[..];
+    assert_eq!(slice.advance_until_delimiter(b": "), b"key");
+    assert_eq!(slice.advance_past_delimiter(b": "), b": ");
+    assert_eq!(slice.advance_past_delimiter(b"\r\n"), b"value\r\n");
+    assert!(matches!(
+        slice.try_advance_until_delimiter(b"\r\n"),
+        Err(AdvanceError::DelimiterNotFound)
+    ));
+    assert!(matches!(
+        slice.try_advance_past_delimiter(b"\r\n"),
+        Err(AdvanceError::DelimiterNotFound)
+    ));
+    assert_eq!(slice, b"rest");
+    assert_eq!(slice.advance_until_delimiter(b""), b"");
+    assert_eq!(slice.advance_to_end(), b"rest");
+    assert_eq!(slice.advance_to_end(), b"");
+
+    let mut slice = &mut data[..];
+    slice.advance_past_delimiter(b": ").fill(b'_');
+    slice.advance_to_end().make_ascii_uppercase();
+    assert_eq!(&data, b"_____VALUE\r\nREST");
+}
+
+#[test]
+fn strip_delimiters() {
+    let mut data = *b"key: value\r\nrest";
+    let mut slice = &data[..];
+    let key = slice.advance_strip_delimiter(b": ");
+    let value = slice.advance_strip_delimiter(b"\r\n");
+    assert_eq!((key, value), (&b"key"[..], &b"value"[..]));
+    assert!(matches!(
+        slice.try_advance_strip_delimiter(b"\r\n"),
+        Err(AdvanceError::DelimiterNotFound)
+    ));
+    assert_eq!(slice, b"rest");
+    assert_eq!(slice.advance_strip_delimiter(b""), b"");
+    assert_eq!(slice, b"rest");
+
+    let mut cursor = Cursor::new(&data[..]);
+    assert_eq!(cursor.advance_strip_delimiter(b"value"), b"key: ");
+    assert_eq!(cursor.position(), 10);
+
+    let mut slice = &mut data[..];
+    slice.advance_strip_delimiter(b": ").fill(b'_');
+    slice
+        .advance_strip_delimiter(b"\r\n")
+        .make_ascii_uppercase();
+    assert_eq!(&data, b"___: VALUE\r\nrest");
+}
+
+#[test]
+#[should_panic(expected = "Delimiter not found")]
+fn panics() {
+    (&mut &b"abc"[..]).advance_until_delimiter(b"d");
+}
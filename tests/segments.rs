@@ -0,0 +1,150 @@
+//! Advancing over data split across several slices.
+
+use advancer::{AdvanceArray, AdvanceBytes, AdvanceError, Length, SegmentArray, Segments};
+
+#[test]
+fn arrays_across_boundaries() {
+    let segments: [&[u8]; 3] = [&[1, 2, 3], &[4], &[5, 6, 7, 8]];
+    let mut data = Segments::new(&segments);
+    assert_eq!(data.len(), 8);
+    assert!(matches!(
+        data.advance_array::<2>(),
+        SegmentArray::Borrowed(&[1, 2])
+    ));
+    // Crosses all three segments
+    assert!(matches!(
+        data.advance_array::<4>(),
+        SegmentArray::Owned([3, 4, 5, 6])
+    ));
+    assert!(matches!(
+        data.advance_array::<1>(),
+        SegmentArray::Borrowed(&[7])
+    ));
+    assert_eq!(data.len(), 1);
+    assert!(matches!(
+        data.try_advance_array::<2>(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+    assert_eq!(*data.advance_array::<1>(), [8]);
+    assert!(data.is_empty());
+    assert_eq!(*data.advance_array::<0>(), []);
+}
+
+#[test]
+fn reads_across_boundaries() {
+    let bytes = 0x0403_0201u32.to_le_bytes();
+    let (first, second) = bytes.split_at(1);
+    let mut data = Segments::from_pair(first, second);
+    assert_eq!(data.read_u32_le(), 0x0403_0201);
+
+    let (first, second) = bytes.split_at(3);
+    let mut data = Segments::from((first, second));
+    assert_eq!(data.read_u16_be(), 0x0102);
+    assert_eq!(data.read_u16_le(), 0x0403);
+    assert!(data.try_read_u8().is_err());
+}
+
+#[test]
+fn empty_segments() {
+    let segments: [&[u8]; 6] = [&[], &[1], &[], &[], &[2, 3], &[]];
+    let mut data = Segments::new(&segments);
+    assert_eq!(data.len(), 3);
+    assert!(matches!(
+        data.advance_array::<2>(),
+        SegmentArray::Owned([1, 2])
+    ));
+    assert!(matches!(
+        data.advance_array::<1>(),
+        SegmentArray::Borrowed(&[3])
+    ));
+    assert!(data.try_read_u8().is_err());
+
+    assert!(Segments::<u8>::new(&[]).is_empty());
+    let mut data = Segments::from_pair(&[][..], &[1u8][..]);
+    assert!(matches!(
+        data.advance_array::<1>(),
+        SegmentArray::Borrowed(&[1])
+    ));
+}
+
+#[test]
+fn scratch() {
+    let segments: [&[u8]; 3] = [&[1, 2], &[3, 4], &[5]];
+    let mut data = Segments::new(&segments);
+    let mut scratch = [0u8; 3];
+    assert_eq!(data.advance_scratch(1, &mut scratch), [1]);
+    assert_eq!(data.advance_scratch(2, &mut scratch), [2, 3]);
+    // The unused end of the scratch space is untouched
+    assert_eq!(scratch, [2, 3, 0]);
+    assert!(matches!(
+        data.try_advance_scratch(3, &mut scratch),
+        Err(AdvanceError::NotEnoughData {
+            needed: 3,
+            remaining: 2
+        })
+    ));
+    assert_eq!(data.advance_scratch(2, &mut scratch), [4, 5]);
+}
+
+#[test]
+fn scratch_too_small() {
+    let segments: [&[u8]; 3] = [&[1, 2], &[3, 4], &[5]];
+    let mut data = Segments::new(&segments);
+    let mut scratch = [0u8; 2];
+    assert!(matches!(
+        data.try_advance_scratch(3, &mut scratch),
+        Err(AdvanceError::ScratchTooSmall {
+            needed: 3,
+            capacity: 2
+        })
+    ));
+    // Nothing is advanced over
+    assert_eq!(data.len(), 5);
+    assert_eq!(scratch, [0, 0]);
+    // Borrowed portions need no scratch space at all
+    assert_eq!(data.advance_scratch(2, &mut []), [1, 2]);
+    assert_eq!(data.advance_scratch(3, &mut [0; 3]), [3, 4, 5]);
+}
+
+#[test]
+#[should_panic(expected = "Scratch")]
+fn scratch_panics() {
+    let segments: [&[u8]; 2] = [&[1], &[2]];
+    Segments::new(&segments).advance_scratch(2, &mut [0]);
+}
+
+#[cfg(feature = "alloc")]
+mod cow {
+    use advancer::{Advance, AdvanceError, Length, Segments};
+    use std::borrow::Cow;
+
+    #[test]
+    fn borrowed_and_owned() {
+        let segments: [&[u16]; 4] = [&[1, 2, 3], &[], &[4], &[5, 6]];
+        let mut data = Segments::new(&segments);
+        assert!(matches!(data.advance(2), Cow::Borrowed(&[1, 2])));
+        assert!(matches!(data.advance(0), Cow::Borrowed(&[])));
+        let owned = data.advance(3);
+        assert!(matches!(&owned, Cow::Owned(vec) if *vec == [3, 4, 5]));
+        assert!(matches!(data.advance(1), Cow::Borrowed(&[6])));
+        assert!(matches!(
+            data.try_advance(1),
+            Err(AdvanceError::NotEnoughData {
+                needed: 1,
+                remaining: 0
+            })
+        ));
+        assert_eq!(data.len(), 0);
+    }
+
+    #[test]
+    fn strings() {
+        let segments: [&[String]; 2] = [&[String::from("a")], &[String::from("b")]];
+        let mut data = Segments::new(&segments);
+        assert_eq!(data.clone().advance(2).into_owned(), ["a", "b"]);
+        assert!(matches!(data.advance(1), Cow::Borrowed([a]) if a == "a"));
+    }
+}
@@ -0,0 +1,90 @@
+//! Reference counted byte buffers.
+
+#![cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
+
+use advancer::{Advance, AdvanceArray, AdvanceBytes, AdvanceError, SharedBytes};
+use std::collections::HashSet;
+use std::sync::Arc;
+
+#[test]
+fn advance_shares_the_allocation() {
+    let data: Arc<[u8]> = Arc::from(&[1u8, 2, 3, 4, 5, 6][..]);
+    let mut bytes = SharedBytes::new(data.clone());
+    let first = bytes.advance(2);
+    let array = bytes.advance_array::<3>();
+    assert_eq!(first, SharedBytes::from(&[1u8, 2][..]));
+    assert_eq!(*array, [3, 4, 5]);
+    assert_eq!(array.as_ref(), [3, 4, 5]);
+    assert_eq!(bytes.as_slice(), [6]);
+    // Every handle points into the original allocation
+    assert_eq!(first.as_ptr(), data.as_ptr());
+    assert_eq!(array.as_ptr(), data[2..].as_ptr());
+    assert_eq!(bytes.as_ptr(), data[5..].as_ptr());
+    assert_eq!(Arc::strong_count(&data), 4);
+    assert_eq!(array.into_bytes().as_ptr(), data[2..].as_ptr());
+
+    assert!(matches!(
+        bytes.try_advance(2),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+    assert_eq!(bytes.read_u8(), 6);
+    assert!(bytes.is_empty());
+}
+
+#[test]
+fn slice() {
+    let bytes = SharedBytes::from(vec![0u8, 1, 2, 3, 4]);
+    assert_eq!(bytes.slice(..).as_slice(), [0, 1, 2, 3, 4]);
+    assert_eq!(bytes.slice(1..3).as_slice(), [1, 2]);
+    assert_eq!(bytes.slice(1..=3).as_slice(), [1, 2, 3]);
+    assert_eq!(bytes.slice(5..).as_slice(), []);
+    assert_eq!(bytes.slice(2..2).as_slice(), []);
+    // Slices are relative to the handle, not the allocation
+    let inner = bytes.slice(1..);
+    assert_eq!(inner.slice(..2).as_slice(), [1, 2]);
+    assert_eq!(inner.slice(..2).as_ptr(), bytes[1..].as_ptr());
+    assert_eq!(
+        inner.slice((std::ops::Bound::Excluded(0), std::ops::Bound::Unbounded)),
+        bytes.slice(2..)
+    );
+}
+
+#[test]
+#[should_panic(expected = "range `2..6` out of bounds for length `5`")]
+fn slice_past_end() {
+    SharedBytes::from(vec![0u8; 5]).slice(2..6);
+}
+
+#[test]
+#[should_panic(expected = "range `3..2` out of bounds for length `5`")]
+fn slice_reversed() {
+    #[allow(clippy::reversed_empty_ranges)]
+    SharedBytes::from(vec![0u8; 5]).slice(3..2);
+}
+
+#[test]
+#[should_panic(expected = "range `0..4` out of bounds for length `3`")]
+fn slice_past_end_of_handle() {
+    // In bounds for the allocation, but not for the handle
+    SharedBytes::from(vec![0u8; 5]).slice(1..4).slice(..=3);
+}
+
+#[test]
+#[should_panic(expected = "range end overflows")]
+fn slice_end_overflows() {
+    SharedBytes::from(vec![0u8; 5]).slice(..=usize::MAX);
+}
+
+#[test]
+fn compares_bytes() {
+    let a = SharedBytes::from(&b"abcabc"[..]);
+    let b = SharedBytes::from(Box::<[u8]>::from(&b"abc"[..]));
+    assert_eq!(a.slice(3..), b);
+    assert_ne!(a.slice(..2), b);
+    let set: HashSet<_> = [a.slice(..3), a.slice(3..), b].into_iter().collect();
+    assert_eq!(set.len(), 1);
+    assert_eq!(format!("{:?}", a.slice(..2)), "[97, 98]");
+}
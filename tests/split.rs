@@ -0,0 +1,128 @@
+//! Advancing over several regions with one bounds check.
+
+use advancer::{AdvanceError, AdvanceSplit};
+
+#[test]
+fn many() {
+    let data = [1, 2, 3, 4, 5, 6];
+    let mut slice = &data[..];
+    let [a, b, c] = slice.advance_many([1, 0, 3]);
+    assert_eq!((a, b, c), (&[1][..], &[][..], &[2, 3, 4][..]));
+    assert_eq!(slice, [5, 6]);
+    let [] = slice.advance_many([]);
+    assert_eq!(slice.len(), 2);
+}
+
+#[test]
+fn many_not_enough_data() {
+    let data = [1, 2, 3, 4];
+    let mut slice = &data[..];
+    assert!(matches!(
+        slice.try_advance_many([2, 3]),
+        Err(AdvanceError::NotEnoughData {
+            needed: 5,
+            remaining: 4
+        })
+    ));
+    assert_eq!(slice, data);
+}
+
+#[test]
+fn many_overflow() {
+    let mut data = [1, 2, 3, 4];
+    let mut slice = &data[..];
+    for amounts in [[usize::MAX, 1], [1, usize::MAX], [usize::MAX, usize::MAX]] {
+        assert!(matches!(
+            slice.try_advance_many(amounts),
+            Err(AdvanceError::NotEnoughData {
+                needed: usize::MAX,
+                remaining: 4
+            })
+        ));
+        assert_eq!(slice, [1, 2, 3, 4]);
+    }
+
+    let mut slice = &mut data[..];
+    assert!(slice
+        .try_advance_many([usize::MAX / 2 + 1, usize::MAX / 2 + 1])
+        .is_err());
+    assert_eq!(slice.len(), 4);
+}
+
+#[test]
+fn many_overflow_zero_sized() {
+    let mut data = [(); usize::MAX];
+    let mut slice = &mut data[..];
+    for amounts in [[usize::MAX, 5], [usize::MAX, usize::MAX]] {
+        assert!(matches!(
+            slice.try_advance_many(amounts),
+            Err(AdvanceError::NotEnoughData {
+                needed: usize::MAX,
+                remaining: usize::MAX
+            })
+        ));
+        assert_eq!(slice.len(), usize::MAX);
+    }
+    let [a, b] = slice.advance_many([usize::MAX - 5, 5]);
+    assert_eq!((a.len(), b.len()), (usize::MAX - 5, 5));
+    assert!(slice.is_empty());
+
+    let mut slice = &[(); usize::MAX][..];
+    assert!(slice.try_advance_many([usize::MAX, 1]).is_err());
+    assert_eq!(slice.len(), usize::MAX);
+}
+
+#[test]
+#[should_panic]
+fn many_overflow_zero_sized_panics() {
+    (&mut &mut [(); usize::MAX][..]).advance_many([usize::MAX, 5]);
+}
+
+#[test]
+fn many_mut() {
+    let mut data = [1, 2, 3, 4, 5];
+    let mut slice = &mut data[..];
+    let [a, b] = slice.advance_many([2, 2]);
+    a.swap(0, 1);
+    b[0] = 30;
+    assert!(slice.try_advance_many([1, 1]).is_err());
+    assert_eq!(slice.len(), 1);
+    assert_eq!(data, [2, 1, 30, 4, 5]);
+}
+
+#[test]
+fn split() {
+    let data = [1u8, 2, 3, 4, 5, 6, 7];
+    let mut slice = &data[..];
+    let (a, b, c) = slice.advance_split::<([u8; 1], [u8; 2], [u8; 3])>();
+    assert_eq!((a, b, c), (&[1], &[2, 3], &[4, 5, 6]));
+    assert_eq!(slice, [7]);
+    assert!(matches!(
+        slice.try_advance_split::<([u8; 1], [u8; 1])>(),
+        Err(AdvanceError::NotEnoughData {
+            needed: 2,
+            remaining: 1
+        })
+    ));
+    assert_eq!(slice, [7]);
+    let (empty,) = slice.advance_split::<([u8; 0],)>();
+    assert_eq!(empty, &[]);
+}
+
+#[test]
+fn split_mut() {
+    let mut data = [0u16; 6];
+    let mut slice = &mut data[..];
+    let (a, b) = slice.advance_split::<([u16; 2], [u16; 3])>();
+    a.fill(1);
+    b.fill(2);
+    assert!(slice.try_advance_split::<([u16; 2],)>().is_err());
+    slice[0] = 3;
+    assert_eq!(data, [1, 1, 2, 2, 2, 3]);
+}
+
+#[test]
+#[should_panic(expected = "needed: `5`")]
+fn panics() {
+    (&mut &[0u8; 4][..]).advance_many([5]);
+}
@@ -0,0 +1,158 @@
+//! Reading and writing UTF-8 strings.
+
+use advancer::prefix::U8;
+use advancer::{AdvanceError, AdvanceStr, AdvanceStrWrite, Cursor};
+
+#[test]
+fn str() {
+    let mut reader = &b"hello world"[..];
+    assert_eq!(reader.advance_str(5), "hello");
+    assert_eq!(reader.advance_str(0), "");
+    assert!(matches!(
+        reader.try_advance_str(7),
+        Err(AdvanceError::NotEnoughData {
+            needed: 7,
+            remaining: 6
+        })
+    ));
+    assert_eq!(reader.advance_str(6), " world");
+}
+
+#[test]
+fn invalid_utf8() {
+    let mut reader = &[b'a', b'b', 0xff, b'c'][..];
+    assert!(matches!(
+        reader.try_advance_str(4),
+        Err(AdvanceError::InvalidUtf8 { offset: 2 })
+    ));
+    assert!(matches!(
+        reader.try_advance_padded_str::<4>(0),
+        Err(AdvanceError::InvalidUtf8 { offset: 2 })
+    ));
+    assert!(matches!(
+        reader.try_advance_len_prefixed_str::<U8>(),
+        Err(AdvanceError::NotEnoughData { .. })
+    ));
+    // Nothing is advanced over, not even the length prefix
+    assert_eq!(reader, [b'a', b'b', 0xff, b'c']);
+    let mut reader = &[3, b'a', 0xff, b'c'][..];
+    assert!(matches!(
+        reader.try_advance_len_prefixed_str::<U8>(),
+        Err(AdvanceError::InvalidUtf8 { offset: 2 })
+    ));
+    assert_eq!(reader.len(), 4);
+
+    let mut reader = &mut [b'a', 0xc3, 0x28, 0][..];
+    assert!(matches!(
+        reader.try_advance_cstr(),
+        Err(AdvanceError::InvalidUtf8 { offset: 1 })
+    ));
+    assert_eq!(reader.len(), 4);
+    // A split char is invalid, even if the whole buffer is valid
+    let mut reader = "é".as_bytes();
+    assert!(reader.try_advance_str(1).is_err());
+    assert_eq!(reader.advance_str(2), "é");
+}
+
+#[test]
+fn padded() {
+    let mut reader = &b"abc\0\0\0xy  "[..];
+    assert_eq!(reader.advance_padded_str::<6>(0), "abc");
+    assert_eq!(reader.advance_padded_str::<4>(b' '), "xy");
+    assert!(reader.is_empty());
+    // Padding inside the string is kept
+    assert_eq!((&b"a\0b\0"[..]).advance_padded_str::<4>(0), "a\0b");
+    assert_eq!((&b"\0\0"[..]).advance_padded_str::<2>(0), "");
+}
+
+#[test]
+fn cstr() {
+    let mut reader = Cursor::new(&b"abc\0\0de"[..]);
+    assert_eq!(reader.advance_cstr(), "abc");
+    assert_eq!(reader.advance_cstr(), "");
+    assert!(matches!(
+        reader.try_advance_cstr(),
+        Err(AdvanceError::MissingNulAt {
+            offset: 5,
+            length: 7
+        })
+    ));
+    assert_eq!(reader.position(), 5);
+    assert!(matches!(
+        (&b"de"[..]).try_advance_cstr(),
+        Err(AdvanceError::MissingNul)
+    ));
+}
+
+#[test]
+fn len_prefixed() {
+    let mut reader = &b"\x03abc\x00\x02d"[..];
+    assert_eq!(reader.advance_len_prefixed_str::<U8>(), "abc");
+    assert_eq!(reader.advance_len_prefixed_str::<U8>(), "");
+    assert!(reader.try_advance_len_prefixed_str::<U8>().is_err());
+    assert_eq!(reader, b"\x02d");
+}
+
+#[test]
+fn write_padded() {
+    let mut buffer = [0xaa; 12];
+    let mut writer = &mut buffer[..];
+    writer.write_padded_str::<4>("ab", 0).unwrap();
+    writer.write_padded_str::<2>("abc", 0).unwrap();
+    // "é" is 2 bytes and does not fit after "a" in 2 bytes
+    writer.write_padded_str::<2>("aé", b' ').unwrap();
+    writer.write_padded_str::<4>("éé", b'-').unwrap();
+    assert!(writer.write_padded_str::<1>("", 0).is_err());
+    assert_eq!(&buffer, b"ab\0\0aba \xc3\xa9\xc3\xa9");
+
+    let mut reader = &buffer[..];
+    assert_eq!(reader.advance_padded_str::<4>(0), "ab");
+    assert_eq!(reader.advance_padded_str::<2>(0), "ab");
+    assert_eq!(reader.advance_padded_str::<2>(b' '), "a");
+    assert_eq!(reader.advance_padded_str::<4>(b'-'), "éé");
+}
+
+#[test]
+fn write_cstr() {
+    let mut buffer = [0xaa; 6];
+    let mut writer = &mut buffer[..];
+    writer.write_cstr("abc").unwrap();
+    assert!(matches!(
+        writer.write_cstr("a\0"),
+        Err(AdvanceError::InteriorNul { index: 1 })
+    ));
+    assert!(writer.write_cstr("abc").is_err());
+    assert_eq!(writer.len(), 2);
+    writer.write_cstr("d").unwrap();
+    assert_eq!(&buffer, b"abc\0d\0");
+
+    let mut reader = &buffer[..];
+    assert_eq!(reader.advance_cstr(), "abc");
+    assert_eq!(reader.advance_cstr(), "d");
+}
+
+#[test]
+#[should_panic(expected = "offset: `2`")]
+fn panics() {
+    (&[b'a', b'b', 0xff][..]).advance_str(3);
+}
+
+#[test]
+fn cursor_offsets() {
+    let bytes = b"ab\0cd\xffe";
+    let mut cursor = Cursor::new(&bytes[..]);
+    assert_eq!(cursor.advance_cstr(), "ab");
+    assert!(matches!(
+        cursor.try_advance_str(4),
+        Err(AdvanceError::InvalidUtf8 { offset: 5 })
+    ));
+    assert!(matches!(
+        cursor.try_advance_padded_str::<4>(0),
+        Err(AdvanceError::InvalidUtf8 { offset: 5 })
+    ));
+    assert_eq!(cursor.position(), 3);
+    assert!(matches!(
+        (&bytes[3..]).try_advance_str(4),
+        Err(AdvanceError::InvalidUtf8 { offset: 2 })
+    ));
+}
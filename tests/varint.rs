@@ -0,0 +1,133 @@
+//! LEB128 varints with canonical encoding checks.
+
+use advancer::{AdvanceError, AdvanceVarint, AdvanceVarintWrite};
+
+/// Encodes with `write` into a fresh buffer, returning the written bytes.
+fn encode(write: impl FnOnce(&mut &mut [u8]) -> Result<(), AdvanceError>) -> Vec<u8> {
+    let mut buffer = [0u8; 32];
+    let mut writer = &mut buffer[..];
+    write(&mut writer).unwrap();
+    let written = 32 - writer.len();
+    buffer[..written].to_vec()
+}
+
+macro_rules! unsigned {
+    ($($name:ident: $ty:ty => $read:ident, $write:ident;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                let len = <$ty>::BITS.div_ceil(7) as usize;
+                for value in [0, 1, 0x7f, 0x80, <$ty>::MAX / 3, <$ty>::MAX - 1, <$ty>::MAX] {
+                    let bytes = encode(|writer| writer.$write(value));
+                    let mut reader = &bytes[..];
+                    assert_eq!(reader.$read().unwrap(), value);
+                    assert!(reader.is_empty());
+                }
+
+                let max = encode(|writer| writer.$write(<$ty>::MAX));
+                assert_eq!(max.len(), len);
+                assert!(max[..len - 1].iter().all(|byte| *byte == 0xff));
+
+                // A final byte setting bits past the width
+                let mut bytes = max.clone();
+                bytes[len - 1] += 1;
+                assert!(matches!(
+                    (&bytes[..]).$read(),
+                    Err(AdvanceError::VarintOverflow { bits }) if bits == <$ty>::BITS
+                ));
+                // More bytes than the width can need
+                let mut bytes = vec![0x80; len];
+                bytes.push(0);
+                assert!(matches!(
+                    (&bytes[..]).$read(),
+                    Err(AdvanceError::VarintOverflow { bits }) if bits == <$ty>::BITS
+                ));
+                // Trailing zero bytes
+                for bytes in [&[0x80, 0][..], &[0xff, 0x80, 0]] {
+                    if bytes.len() <= len {
+                        assert!(matches!(
+                            (&bytes[..]).$read(),
+                            Err(AdvanceError::VarintOverlong)
+                        ));
+                    }
+                }
+                assert!(matches!(
+                    (&[0x80u8][..]).$read(),
+                    Err(AdvanceError::NotEnoughData {
+                        needed: 1,
+                        remaining: 0
+                    })
+                ));
+            }
+        )*
+    };
+}
+
+unsigned! {
+    unsigned_u8: u8 => try_read_varint_u8, write_varint_u8;
+    unsigned_u16: u16 => try_read_varint_u16, write_varint_u16;
+    unsigned_u32: u32 => try_read_varint_u32, write_varint_u32;
+    unsigned_u64: u64 => try_read_varint_u64, write_varint_u64;
+    unsigned_u128: u128 => try_read_varint_u128, write_varint_u128;
+    unsigned_usize: usize => try_read_varint_usize, write_varint_usize;
+}
+
+macro_rules! signed {
+    ($($name:ident: $ty:ty => $read:ident, $write:ident, $write_unsigned:ident, $unsigned:ty;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                for value in [0, -1, 1, -64, 64, <$ty>::MIN, <$ty>::MIN + 1, <$ty>::MAX - 1, <$ty>::MAX] {
+                    let bytes = encode(|writer| writer.$write(value));
+                    let mut reader = &bytes[..];
+                    assert_eq!(reader.$read().unwrap(), value);
+                    assert!(reader.is_empty());
+                }
+                assert_eq!(encode(|writer| writer.$write(0)), [0]);
+                assert_eq!(encode(|writer| writer.$write(-1)), [1]);
+                assert_eq!(encode(|writer| writer.$write(1)), [2]);
+                assert_eq!(
+                    encode(|writer| writer.$write(<$ty>::MAX)),
+                    encode(|writer| writer.$write_unsigned(<$unsigned>::MAX - 1))
+                );
+                assert_eq!(
+                    encode(|writer| writer.$write(<$ty>::MIN)),
+                    encode(|writer| writer.$write_unsigned(<$unsigned>::MAX))
+                );
+            }
+        )*
+    };
+}
+
+signed! {
+    signed_i8: i8 => try_read_varint_i8, write_varint_i8, write_varint_u8, u8;
+    signed_i16: i16 => try_read_varint_i16, write_varint_i16, write_varint_u16, u16;
+    signed_i32: i32 => try_read_varint_i32, write_varint_i32, write_varint_u32, u32;
+    signed_i64: i64 => try_read_varint_i64, write_varint_i64, write_varint_u64, u64;
+    signed_i128: i128 => try_read_varint_i128, write_varint_i128, write_varint_u128, u128;
+    signed_isize: isize => try_read_varint_isize, write_varint_isize, write_varint_usize, usize;
+}
+
+#[test]
+fn known_encodings() {
+    assert_eq!(encode(|writer| writer.write_varint_u32(300)), [0xac, 0x02]);
+    assert_eq!(
+        encode(|writer| writer.write_varint_u64(624485)),
+        [0xe5, 0x8e, 0x26]
+    );
+    assert_eq!((&[0xe5, 0x8e, 0x26][..]).read_varint_u32(), 624485);
+    assert_eq!((&[0x7f][..]).read_varint_i8(), -64);
+}
+
+#[test]
+fn not_enough_space() {
+    let mut buffer = [0u8; 2];
+    let mut writer = &mut buffer[..];
+    assert!(writer.write_varint_u32(1 << 14).is_err());
+}
+
+#[test]
+#[should_panic(expected = "Overlong varint")]
+fn panics() {
+    (&[0x80, 0][..]).read_varint_u16();
+}
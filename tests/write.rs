@@ -0,0 +1,43 @@
+//! Advancing writer for mutable byte slices.
+
+use advancer::{AdvanceBytes, AdvanceError, AdvanceWrite};
+
+#[test]
+fn round_trip() {
+    let mut buffer = [0u8; 32];
+    let mut writer = &mut buffer[..];
+    writer.write_u8(7).unwrap();
+    writer.write_u16_be(0x0102).unwrap();
+    writer.write_i32_le(-5).unwrap();
+    writer.write_f64_le(2.5).unwrap();
+    writer.write_bool(true).unwrap();
+    writer.write_bytes(b"ab").unwrap();
+    writer.write_fill(2, 0xee).unwrap();
+    writer.write_zeroed(1).unwrap();
+    assert_eq!(writer.len(), 32 - 21);
+
+    let mut reader = &buffer[..];
+    assert_eq!(reader.read_u8(), 7);
+    assert_eq!(reader.read_array::<2>(), [1, 2]);
+    assert_eq!(reader.read_i32_le(), -5);
+    assert_eq!(reader.read_f64_le(), 2.5);
+    assert!(reader.read_bool());
+    assert_eq!(reader.read_array::<5>(), [b'a', b'b', 0xee, 0xee, 0]);
+}
+
+#[test]
+fn not_enough_space() {
+    let mut buffer = [0u8; 3];
+    let mut writer = &mut buffer[..];
+    assert!(matches!(
+        writer.write_u32_le(1),
+        Err(AdvanceError::NotEnoughData {
+            needed: 4,
+            remaining: 3
+        })
+    ));
+    assert_eq!(writer.len(), 3);
+    writer.write_u16_le(0xffff).unwrap();
+    assert!(writer.write_fill(2, 1).is_err());
+    assert_eq!(buffer, [0xff, 0xff, 0]);
+}